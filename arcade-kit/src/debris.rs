@@ -0,0 +1,37 @@
+//! Wreckage that breaks a vector outline into tumbling line segments.
+//!
+//! The pieces only drift and spin here; each game adds its own world on
+//! top, such as wrap-around, gravity or ground contact.
+
+use glam::Vec2;
+
+/// A spinning piece of a wrecked shape: one segment of its outline.
+#[derive(Clone, Copy, Debug)]
+pub struct Debris {
+    /// Midpoint of the segment.
+    pub pos: Vec2,
+    pub vel: Vec2,
+    pub angle: f32,
+    pub spin: f32,
+    pub half_len: f32,
+}
+
+impl Debris {
+    /// A piece made from the segment `a`-`b`, flying off at `vel`.
+    pub fn from_segment(a: Vec2, b: Vec2, vel: Vec2, spin: f32) -> Self {
+        let d = b - a;
+        Self { pos: (a + b) * 0.5, vel, angle: d.y.atan2(d.x), spin, half_len: d.length() * 0.5 }
+    }
+
+    /// Move and turn the piece for `dt` seconds.
+    pub fn drift(&mut self, dt: f32) {
+        self.pos += self.vel * dt;
+        self.angle += self.spin * dt;
+    }
+
+    /// Current endpoints of the segment.
+    pub fn endpoints(&self) -> (Vec2, Vec2) {
+        let half = Vec2::from_angle(self.angle) * self.half_len;
+        (self.pos - half, self.pos + half)
+    }
+}
@@ -0,0 +1,41 @@
+//! Fixed key/axis sets and compact key bitmasks.
+
+use vectorcade_shared::input::{Axis, Key};
+
+/// Keys the games read, in bit order for [`KeyMask`].
+pub const KEYS: [Key; 10] = [
+    Key::Left,
+    Key::Right,
+    Key::Up,
+    Key::Down,
+    Key::W,
+    Key::S,
+    Key::Space,
+    Key::Enter,
+    Key::Escape,
+    Key::A,
+];
+
+/// Analog axes captured alongside the keys.
+pub const AXES: [Axis; 2] = [Axis::LeftX, Axis::LeftY];
+
+/// One bit per entry of [`KEYS`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KeyMask(pub u16);
+
+impl KeyMask {
+    pub const NONE: KeyMask = KeyMask(0);
+
+    /// Build a mask from a per-key predicate.
+    pub fn from_fn(mut f: impl FnMut(Key) -> bool) -> Self {
+        let mut bits = 0;
+        for (i, &k) in KEYS.iter().enumerate() {
+            if f(k) { bits |= 1 << i; }
+        }
+        Self(bits)
+    }
+
+    pub fn contains(self, key: Key) -> bool {
+        KEYS.iter().position(|&k| k == key).is_some_and(|i| self.0 & (1 << i) != 0)
+    }
+}
@@ -0,0 +1,11 @@
+//! Shared helpers for VectorCade game crates.
+//!
+//! Things every game needs but that don't belong in `vectorcade-shared`:
+//! input snapshots, deterministic replays, a fixed-step clock, the sound
+//! event catalogue and outline debris for wrecks.
+
+pub mod debris;
+pub mod input;
+pub mod replay;
+pub mod sound;
+pub mod timestep;
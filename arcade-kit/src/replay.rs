@@ -0,0 +1,286 @@
+//! Deterministic input recording and playback.
+//!
+//! A [`Replay`] is the RNG seed plus one [`ReplayFrame`] per `Game::update`
+//! call. Recording side contract: seed the session's RNG with
+//! `Xorshift64::new(seed)`, call `reset` once, then `record` before every
+//! `update`. [`Player`] repeats exactly that against any `Game`.
+//!
+//! Binary layout (little endian):
+//!
+//! ```text
+//! header  "VCRP" | version u8 | seed u64 | frame count u32
+//! frame   dt f32 | down u16 | pressed u16 | released u16
+//!         axis mask u8 | f32 per set bit
+//!         pointer u8 (0 none, 1 up, 2 down) | x f32 | y f32 (if present)
+//! ```
+
+use std::fmt;
+
+use glam::Vec2;
+use vectorcade_shared::{
+    Xorshift64,
+    game::{AudioOut, Game, GameCtx, ScreenInfo},
+    input::{Axis, Button, InputState, Key, Pointer},
+};
+
+use crate::input::{AXES, KeyMask};
+
+const MAGIC: &[u8; 4] = b"VCRP";
+pub const FORMAT_VERSION: u8 = 2;
+
+/// Pointer state as stored in a replay.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PointerSample {
+    pub pos: Vec2,
+    pub down: bool,
+}
+
+/// Input seen by one `update` call.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReplayFrame {
+    pub dt: f32,
+    pub down: KeyMask,
+    pub pressed: KeyMask,
+    pub released: KeyMask,
+    pub axes: [f32; AXES.len()],
+    pub pointer: Option<PointerSample>,
+}
+
+impl ReplayFrame {
+    /// Snapshot the live input state.
+    pub fn capture(input: &dyn InputState, dt: f32) -> Self {
+        Self {
+            dt,
+            down: KeyMask::from_fn(|k| input.key(k).is_down),
+            pressed: KeyMask::from_fn(|k| input.key(k).went_down),
+            released: KeyMask::from_fn(|k| input.key(k).went_up),
+            axes: AXES.map(|a| input.axis(a)),
+            pointer: input.pointer().map(|p| PointerSample { pos: p.pos, down: p.down }),
+        }
+    }
+}
+
+impl InputState for ReplayFrame {
+    fn key(&self, k: Key) -> Button {
+        Button {
+            is_down: self.down.contains(k),
+            went_down: self.pressed.contains(k),
+            went_up: self.released.contains(k),
+        }
+    }
+
+    fn axis(&self, a: Axis) -> f32 {
+        AXES.iter().position(|&x| x == a).map_or(0.0, |i| self.axes[i])
+    }
+
+    fn pointer(&self) -> Option<Pointer> {
+        self.pointer.map(|p| Pointer { pos: p.pos, down: p.down })
+    }
+}
+
+/// Reasons a byte stream is not a readable replay.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplayError {
+    BadMagic,
+    UnsupportedVersion(u8),
+    Truncated,
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadMagic => write!(f, "not a replay file"),
+            Self::UnsupportedVersion(v) => write!(f, "unsupported replay version {}", v),
+            Self::Truncated => write!(f, "replay data is truncated"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// A recorded play session.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Replay {
+    pub seed: u64,
+    pub frames: Vec<ReplayFrame>,
+}
+
+impl Replay {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, frames: Vec::new() }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(17 + self.frames.len() * 11);
+        out.extend_from_slice(MAGIC);
+        out.push(FORMAT_VERSION);
+        out.extend_from_slice(&self.seed.to_le_bytes());
+        out.extend_from_slice(&(self.frames.len() as u32).to_le_bytes());
+        for f in &self.frames {
+            out.extend_from_slice(&f.dt.to_le_bytes());
+            for mask in [f.down, f.pressed, f.released] {
+                out.extend_from_slice(&mask.0.to_le_bytes());
+            }
+            let axis_mask = f.axes.iter().enumerate()
+                .filter(|(_, v)| **v != 0.0)
+                .fold(0u8, |m, (i, _)| m | (1 << i));
+            out.push(axis_mask);
+            for v in f.axes.iter().filter(|v| **v != 0.0) {
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+            match f.pointer {
+                None => out.push(0),
+                Some(p) => {
+                    out.push(if p.down { 2 } else { 1 });
+                    out.extend_from_slice(&p.pos.x.to_le_bytes());
+                    out.extend_from_slice(&p.pos.y.to_le_bytes());
+                }
+            }
+        }
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ReplayError> {
+        let mut r = Reader { bytes, at: 0 };
+        if r.take(4)? != MAGIC { return Err(ReplayError::BadMagic); }
+        let version = r.u8()?;
+        if version != FORMAT_VERSION { return Err(ReplayError::UnsupportedVersion(version)); }
+        let seed = u64::from_le_bytes(r.array()?);
+        let count = u32::from_le_bytes(r.array()?) as usize;
+        let mut frames = Vec::with_capacity(count.min(bytes.len() / 11));
+        for _ in 0..count {
+            let dt = r.f32()?;
+            let down = KeyMask(u16::from_le_bytes(r.array()?));
+            let pressed = KeyMask(u16::from_le_bytes(r.array()?));
+            let released = KeyMask(u16::from_le_bytes(r.array()?));
+            let axis_mask = r.u8()?;
+            let mut axes = [0.0; AXES.len()];
+            for (i, v) in axes.iter_mut().enumerate() {
+                if axis_mask & (1 << i) != 0 { *v = r.f32()?; }
+            }
+            let pointer = match r.u8()? {
+                0 => None,
+                tag => Some(PointerSample { pos: Vec2::new(r.f32()?, r.f32()?), down: tag == 2 }),
+            };
+            frames.push(ReplayFrame { dt, down, pressed, released, axes, pointer });
+        }
+        Ok(Self { seed, frames })
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    at: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], ReplayError> {
+        let end = self.at.checked_add(n).filter(|&e| e <= self.bytes.len()).ok_or(ReplayError::Truncated)?;
+        let slice = &self.bytes[self.at..end];
+        self.at = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], ReplayError> {
+        let mut buf = [0; N];
+        buf.copy_from_slice(self.take(N)?);
+        Ok(buf)
+    }
+
+    fn u8(&mut self) -> Result<u8, ReplayError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn f32(&mut self) -> Result<f32, ReplayError> {
+        Ok(f32::from_le_bytes(self.array()?))
+    }
+}
+
+/// Captures one frame of input per `update` call.
+pub struct Recorder {
+    replay: Replay,
+}
+
+impl Recorder {
+    pub fn new(seed: u64) -> Self {
+        Self { replay: Replay::new(seed) }
+    }
+
+    /// RNG to hand the recorded session, seeded to match playback.
+    pub fn rng(&self) -> Xorshift64 {
+        Xorshift64::new(self.replay.seed)
+    }
+
+    /// Log the input about to be passed to `update(ctx, dt)`.
+    pub fn record(&mut self, input: &dyn InputState, dt: f32) {
+        self.replay.frames.push(ReplayFrame::capture(input, dt));
+    }
+
+    pub fn replay(&self) -> &Replay { &self.replay }
+
+    pub fn finish(self) -> Replay { self.replay }
+}
+
+/// Feeds a replay back into a game frame by frame.
+pub struct Player<'r> {
+    replay: &'r Replay,
+    rng: Xorshift64,
+    next: usize,
+    now_s: f64,
+}
+
+impl<'r> Player<'r> {
+    pub fn new(replay: &'r Replay) -> Self {
+        Self { replay, rng: Xorshift64::new(replay.seed), next: 0, now_s: 0.0 }
+    }
+
+    /// Reseed the RNG and reset the game, ready for the first frame.
+    pub fn reset(&mut self, game: &mut dyn Game, audio: &dyn AudioOut) {
+        self.rng = Xorshift64::new(self.replay.seed);
+        self.next = 0;
+        self.now_s = 0.0;
+        let mut ctx = GameCtx {
+            input: &NoInput,
+            audio,
+            rng: &mut self.rng,
+            screen: ScreenInfo::default(),
+            now_s: 0.0,
+        };
+        game.reset(&mut ctx);
+    }
+
+    /// Run the next recorded frame. Returns `false` once the log is exhausted.
+    pub fn step(&mut self, game: &mut dyn Game, audio: &dyn AudioOut) -> bool {
+        let Some(frame) = self.replay.frames.get(self.next) else { return false };
+        let mut ctx = GameCtx {
+            input: frame,
+            audio,
+            rng: &mut self.rng,
+            screen: ScreenInfo::default(),
+            now_s: self.now_s,
+        };
+        game.update(&mut ctx, frame.dt);
+        self.now_s += frame.dt as f64;
+        self.next += 1;
+        true
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.replay.frames.len()
+    }
+}
+
+/// Reset `game` and play the whole replay into it.
+pub fn play(replay: &Replay, game: &mut dyn Game, audio: &dyn AudioOut) {
+    let mut player = Player::new(replay);
+    player.reset(game, audio);
+    while player.step(game, audio) {}
+}
+
+struct NoInput;
+
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<Pointer> { None }
+}
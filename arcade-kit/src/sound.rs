@@ -0,0 +1,126 @@
+//! Semantic sound events emitted through `AudioOut`.
+//!
+//! Games say *what happened*; the shell maps each stable event ID to a
+//! sample or synth patch.
+
+use std::cell::RefCell;
+
+use vectorcade_shared::game::AudioOut;
+
+/// Size class for explosion sounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Size {
+    Large,
+    Medium,
+    Small,
+}
+
+/// Every sound a game can ask for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoundEvent {
+    PaddleHit,
+    WallBounce,
+    Score,
+    /// Start of a looping engine sound; always paired with `ThrustStop`.
+    ThrustStart,
+    ThrustStop,
+    BulletFire,
+    AsteroidExplode(Size),
+    /// Alternating low/high pulse of the Asteroids heartbeat.
+    HeartbeatLow,
+    HeartbeatHigh,
+    ExtraLife,
+    LanderCrash,
+    LanderLand,
+    TankFire,
+    EnemyDestroyed,
+    FlipperFlip,
+}
+
+impl SoundEvent {
+    pub const ALL: [SoundEvent; 17] = [
+        Self::PaddleHit,
+        Self::WallBounce,
+        Self::Score,
+        Self::ThrustStart,
+        Self::ThrustStop,
+        Self::BulletFire,
+        Self::AsteroidExplode(Size::Large),
+        Self::AsteroidExplode(Size::Medium),
+        Self::AsteroidExplode(Size::Small),
+        Self::HeartbeatLow,
+        Self::HeartbeatHigh,
+        Self::ExtraLife,
+        Self::LanderCrash,
+        Self::LanderLand,
+        Self::TankFire,
+        Self::EnemyDestroyed,
+        Self::FlipperFlip,
+    ];
+
+    /// Stable identifier passed to `AudioOut`.
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::PaddleHit => "paddle_hit",
+            Self::WallBounce => "wall_bounce",
+            Self::Score => "score",
+            Self::ThrustStart => "thrust_start",
+            Self::ThrustStop => "thrust_stop",
+            Self::BulletFire => "bullet_fire",
+            Self::AsteroidExplode(Size::Large) => "asteroid_explode_large",
+            Self::AsteroidExplode(Size::Medium) => "asteroid_explode_medium",
+            Self::AsteroidExplode(Size::Small) => "asteroid_explode_small",
+            Self::HeartbeatLow => "heartbeat_low",
+            Self::HeartbeatHigh => "heartbeat_high",
+            Self::ExtraLife => "extra_life",
+            Self::LanderCrash => "lander_crash",
+            Self::LanderLand => "lander_land",
+            Self::TankFire => "tank_fire",
+            Self::EnemyDestroyed => "enemy_destroyed",
+            Self::FlipperFlip => "flipper_flip",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|e| e.id() == id)
+    }
+}
+
+/// Send `event` to the shell's audio output.
+pub fn emit(audio: &dyn AudioOut, event: SoundEvent) {
+    audio.play_sfx(event.id());
+}
+
+/// `AudioOut` that records events instead of playing them, for tests.
+#[derive(Default)]
+pub struct SoundLog {
+    events: RefCell<Vec<SoundEvent>>,
+}
+
+impl SoundLog {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn events(&self) -> Vec<SoundEvent> {
+        self.events.borrow().clone()
+    }
+
+    pub fn count(&self, event: SoundEvent) -> usize {
+        self.events.borrow().iter().filter(|e| **e == event).count()
+    }
+
+    pub fn contains(&self, event: SoundEvent) -> bool {
+        self.count(event) > 0
+    }
+
+    pub fn clear(&self) {
+        self.events.borrow_mut().clear();
+    }
+}
+
+impl AudioOut for SoundLog {
+    fn play_sfx(&self, id: &str) {
+        if let Some(event) = SoundEvent::from_id(id) {
+            self.events.borrow_mut().push(event);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Fixed-step simulation clock.
+//!
+//! Games keep a [`FixedStep`] and implement [`FixedUpdate`]; their
+//! `Game::update` just calls [`run_fixed`]. The simulation then advances in
+//! whole [`STEP`]s whatever the display rate, so 30, 60 and 144 Hz shells
+//! produce identical state after the same elapsed time.
+//!
+//! To keep motion smooth when the display rate isn't a multiple of the
+//! step, each action game keeps the state of its moving objects from
+//! before the latest step and blends towards the current state by
+//! [`FixedStep::alpha`] in `render`. Teleports (a serve from centre, a
+//! hyperspace jump) reset the previous state so nothing is drawn sliding
+//! across the jump.
+
+use vectorcade_shared::{
+    game::GameCtx,
+    input::{Axis, Button, InputState, Key, Pointer},
+};
+
+use crate::input::{KEYS, KeyMask};
+
+/// Default simulation step (120 Hz).
+pub const STEP: f32 = 1.0 / 120.0;
+/// Longest frame the clock will catch up on; anything beyond is dropped.
+const MAX_FRAME: f32 = 0.25;
+/// Fraction of a step treated as float noise when deciding whether a step is due.
+const SLACK: f32 = 1e-4;
+
+/// Accumulates frame time and hands it out in fixed steps.
+///
+/// Key presses and releases are latched until the next step runs, so an
+/// edge that lands on a frame with no step is not lost, and a frame that
+/// runs several steps reports it only once.
+#[derive(Clone, Debug)]
+pub struct FixedStep {
+    step: f32,
+    accumulator: f32,
+    steps: u64,
+    pressed: KeyMask,
+    released: KeyMask,
+}
+
+impl Default for FixedStep {
+    fn default() -> Self { Self::new(STEP) }
+}
+
+impl FixedStep {
+    pub fn new(step: f32) -> Self {
+        Self { step, accumulator: 0.0, steps: 0, pressed: KeyMask::NONE, released: KeyMask::NONE }
+    }
+
+    pub fn step(&self) -> f32 { self.step }
+
+    /// Total steps run since creation or the last `reset`.
+    pub fn steps(&self) -> u64 { self.steps }
+
+    /// How far (0..1) the display time is past the last step, for
+    /// interpolating between the previous and current simulation state.
+    pub fn alpha(&self) -> f32 {
+        (self.accumulator / self.step).clamp(0.0, 1.0)
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new(self.step);
+    }
+
+    /// Add a frame's time and input edges; returns the number of steps due.
+    fn advance(&mut self, input: &dyn InputState, dt: f32) -> usize {
+        self.pressed.0 |= KeyMask::from_fn(|k| input.key(k).went_down).0;
+        self.released.0 |= KeyMask::from_fn(|k| input.key(k).went_up).0;
+        self.accumulator += dt.clamp(0.0, MAX_FRAME);
+        let mut due = 0;
+        while self.accumulator + self.step * SLACK >= self.step {
+            self.accumulator -= self.step;
+            due += 1;
+        }
+        self.steps += due as u64;
+        due
+    }
+
+    /// Hand out the latched edges (to the first step of a frame).
+    fn take_edges(&mut self) -> (KeyMask, KeyMask) {
+        let edges = (self.pressed, self.released);
+        self.pressed = KeyMask::NONE;
+        self.released = KeyMask::NONE;
+        edges
+    }
+}
+
+/// A game whose simulation advances in fixed steps.
+pub trait FixedUpdate {
+    fn clock(&mut self) -> &mut FixedStep;
+
+    /// Advance the simulation by exactly one step of `dt` seconds.
+    fn fixed_update(&mut self, ctx: &mut GameCtx, dt: f32);
+}
+
+/// Drive `game` for one display frame of `dt` seconds.
+pub fn run_fixed<G: FixedUpdate + ?Sized>(game: &mut G, ctx: &mut GameCtx, dt: f32) {
+    let due = game.clock().advance(ctx.input, dt);
+    let step = game.clock().step();
+    for i in 0..due {
+        let (pressed, released) = if i == 0 { game.clock().take_edges() } else { (KeyMask::NONE, KeyMask::NONE) };
+        let input = StepInput { inner: ctx.input, pressed, released };
+        let mut step_ctx = GameCtx {
+            input: &input,
+            audio: ctx.audio,
+            rng: &mut *ctx.rng,
+            screen: ctx.screen,
+            now_s: ctx.now_s,
+        };
+        game.fixed_update(&mut step_ctx, step);
+    }
+}
+
+/// Live key levels with edges replaced by the clock's latched ones.
+struct StepInput<'a> {
+    inner: &'a dyn InputState,
+    pressed: KeyMask,
+    released: KeyMask,
+}
+
+impl InputState for StepInput<'_> {
+    fn key(&self, k: Key) -> Button {
+        let live = self.inner.key(k);
+        if !KEYS.contains(&k) { return live; }
+        Button { is_down: live.is_down, went_down: self.pressed.contains(k), went_up: self.released.contains(k) }
+    }
+
+    fn axis(&self, a: Axis) -> f32 { self.inner.axis(a) }
+
+    fn pointer(&self) -> Option<Pointer> { self.inner.pointer() }
+}
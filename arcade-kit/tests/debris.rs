@@ -0,0 +1,16 @@
+use arcade_kit::debris::Debris;
+use glam::Vec2;
+
+#[test]
+fn piece_keeps_its_segment_while_tumbling() {
+    let (a, b) = (Vec2::new(0.1, 0.2), Vec2::new(0.4, 0.6));
+    let mut piece = Debris::from_segment(a, b, Vec2::new(1.0, -0.5), 2.0);
+    let (ea, eb) = piece.endpoints();
+    assert!(ea.distance(a) < 1e-6 && eb.distance(b) < 1e-6);
+
+    piece.drift(0.5);
+    assert!(piece.pos.distance(Vec2::new(0.75, 0.15)) < 1e-6);
+    let (ea, eb) = piece.endpoints();
+    assert!((ea.distance(eb) - a.distance(b)).abs() < 1e-5, "length survives the spin");
+    assert!((ea + eb).distance(piece.pos * 2.0) < 1e-5);
+}
@@ -0,0 +1,54 @@
+use arcade_kit::input::KeyMask;
+use arcade_kit::replay::{FORMAT_VERSION, PointerSample, Recorder, Replay, ReplayError, ReplayFrame};
+use glam::Vec2;
+use vectorcade_shared::input::{Axis, Button, InputState, Key, Pointer};
+
+/// Holds Space and pushes the stick right, with the pointer pressed.
+struct Scripted;
+impl InputState for Scripted {
+    fn key(&self, k: Key) -> Button {
+        if k == Key::Space { Button { is_down: true, went_down: true, went_up: false } } else { Button::UP }
+    }
+    fn axis(&self, a: Axis) -> f32 { if a == Axis::LeftX { 0.75 } else { 0.0 } }
+    fn pointer(&self) -> Option<Pointer> { Some(Pointer { pos: Vec2::new(0.25, -0.5), down: true }) }
+}
+
+#[test]
+fn frame_capture_reproduces_input() {
+    let frame = ReplayFrame::capture(&Scripted, 1.0 / 60.0);
+    assert!(frame.key(Key::Space).is_down && frame.key(Key::Space).went_down);
+    assert!(!frame.key(Key::Left).is_down);
+    assert_eq!(frame.axis(Axis::LeftX), 0.75);
+    assert_eq!(frame.pointer, Some(PointerSample { pos: Vec2::new(0.25, -0.5), down: true }));
+}
+
+#[test]
+fn replay_roundtrips_through_bytes() {
+    let mut rec = Recorder::new(0xDEAD_BEEF);
+    rec.record(&Scripted, 1.0 / 60.0);
+    let mut idle = ReplayFrame::capture(&Scripted, 1.0 / 30.0);
+    idle.down = KeyMask::NONE;
+    idle.pressed = KeyMask::NONE;
+    idle.axes = [0.0; 2];
+    idle.pointer = None;
+    let mut replay = rec.finish();
+    replay.frames.push(idle);
+
+    let bytes = replay.to_bytes();
+    assert_eq!(&bytes[..4], b"VCRP");
+    assert_eq!(bytes[4], FORMAT_VERSION);
+    assert_eq!(Replay::from_bytes(&bytes), Ok(replay));
+}
+
+#[test]
+fn replay_rejects_bad_input() {
+    let bytes = Replay::new(7).to_bytes();
+    assert_eq!(Replay::from_bytes(b"NOPE"), Err(ReplayError::BadMagic));
+    let mut future = bytes.clone();
+    future[4] = FORMAT_VERSION + 1;
+    assert_eq!(Replay::from_bytes(&future), Err(ReplayError::UnsupportedVersion(FORMAT_VERSION + 1)));
+    let mut long = Replay::new(7);
+    long.frames.push(ReplayFrame::capture(&Scripted, 0.016));
+    let bytes = long.to_bytes();
+    assert_eq!(Replay::from_bytes(&bytes[..bytes.len() - 1]), Err(ReplayError::Truncated));
+}
@@ -0,0 +1,64 @@
+use arcade_kit::timestep::{FixedStep, FixedUpdate, STEP, run_fixed};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{AudioOut, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+/// Space held down, reported as just pressed when `fresh`.
+struct Space { fresh: bool }
+impl InputState for Space {
+    fn key(&self, k: Key) -> Button {
+        if k == Key::Space { Button { is_down: true, went_down: self.fresh, went_up: false } } else { Button::UP }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+/// Counts steps and the presses each step saw.
+#[derive(Default)]
+struct Counter { clock: FixedStep, steps: u32, presses: u32, time: f32 }
+impl FixedUpdate for Counter {
+    fn clock(&mut self) -> &mut FixedStep { &mut self.clock }
+    fn fixed_update(&mut self, ctx: &mut GameCtx, dt: f32) {
+        self.steps += 1;
+        self.time += dt;
+        if ctx.input.key(Key::Space).went_down { self.presses += 1; }
+    }
+}
+
+fn frame(c: &mut Counter, dt: f32, fresh: bool) {
+    let input = Space { fresh };
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(1);
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    run_fixed(c, &mut ctx, dt);
+}
+
+#[test]
+fn step_count_is_independent_of_display_rate() {
+    for hz in [30, 60, 144, 240] {
+        let mut c = Counter::default();
+        for _ in 0..hz * 3 { frame(&mut c, 1.0 / hz as f32, false); }
+        assert_eq!(c.steps as f32, (3.0 / STEP).round(), "{hz} Hz");
+        assert_eq!(c.clock.steps(), c.steps as u64);
+        assert!(c.clock.alpha() < 0.01);
+    }
+}
+
+#[test]
+fn press_is_latched_until_a_step_runs() {
+    let mut c = Counter::default();
+    frame(&mut c, STEP * 0.4, true);
+    assert_eq!((c.steps, c.presses), (0, 0));
+    frame(&mut c, STEP * 0.7, false);
+    assert_eq!((c.steps, c.presses), (1, 1), "press from the stepless frame is delivered");
+}
+
+#[test]
+fn press_is_seen_once_per_multi_step_frame() {
+    let mut c = Counter::default();
+    frame(&mut c, STEP * 4.0, true);
+    assert_eq!((c.steps, c.presses), (4, 1));
+}
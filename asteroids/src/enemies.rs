@@ -0,0 +1,125 @@
+//! Flying saucer spawning, firing and collisions.
+
+use arcade_kit::sound::{SoundEvent, emit};
+use glam::Vec2;
+use vectorcade_shared::game::GameCtx;
+
+use crate::Asteroids;
+use crate::entities::{Bullet, Saucer, SaucerSize};
+use crate::geometry::{wrapped_delta, wrapped_distance};
+use crate::physics::{SHIP_RADIUS, asteroid_contains, break_asteroid, destroy_ship, spawn_explosion};
+
+const BASE_DELAY: f32 = 14.0;
+const MIN_DELAY: f32 = 5.0;
+const DELAY_PER_LEVEL: f32 = 1.5;
+/// From this score on only small saucers appear, and they aim perfectly.
+const EXPERT_SCORE: u32 = 40_000;
+const MAX_SAUCER_BULLETS: usize = 4;
+
+/// Seconds between saucer appearances on `level`.
+pub fn spawn_delay(level: u8) -> f32 {
+    (BASE_DELAY - DELAY_PER_LEVEL * level.saturating_sub(1) as f32).max(MIN_DELAY)
+}
+
+/// Probability that the next saucer is a small one.
+pub fn small_chance(level: u8, score: u32) -> f32 {
+    if score >= EXPERT_SCORE {
+        return 1.0;
+    }
+    (0.1 + 0.15 * level.saturating_sub(1) as f32).min(0.8)
+}
+
+/// Largest aiming error, in radians, of the small saucer at `score`.
+pub fn aim_error(score: u32) -> f32 {
+    let skill = (score as f32 / EXPERT_SCORE as f32).min(1.0);
+    0.5 * (1.0 - skill)
+}
+
+pub fn update_saucer(game: &mut Asteroids, ctx: &mut GameCtx, dt: f32) {
+    let Some(saucer) = &mut game.saucer else {
+        game.saucer_timer -= dt;
+        if game.saucer_timer <= 0.0 {
+            let small = ctx.rng.range_f32(0.0, 1.0) < small_chance(game.level, game.score);
+            let size = if small { SaucerSize::Small } else { SaucerSize::Large };
+            game.saucer = Some(Saucer::spawn(size, ctx.rng));
+        }
+        return;
+    };
+    if !saucer.update(ctx.rng, dt) {
+        game.saucer = None;
+        game.saucer_timer = spawn_delay(game.level);
+        return;
+    }
+    saucer.fire_timer -= dt;
+    if saucer.fire_timer <= 0.0 && game.saucer_bullets.len() < MAX_SAUCER_BULLETS {
+        saucer.fire_timer = saucer.size.fire_interval();
+        let angle = match saucer.size {
+            SaucerSize::Large => ctx.rng.range_f32(0.0, std::f32::consts::TAU),
+            SaucerSize::Small => {
+                let to_ship = wrapped_delta(saucer.pos, game.ship.pos);
+                let err = aim_error(game.score);
+                to_ship.y.atan2(to_ship.x) + ctx.rng.range_f32(-err, err)
+            }
+        };
+        let dir = Vec2::new(angle.cos(), angle.sin());
+        game.saucer_bullets.push(Bullet::new(saucer.pos + dir * (saucer.size.radius() + 0.01), dir));
+        emit(ctx.audio, SoundEvent::BulletFire);
+    }
+}
+
+pub fn handle_saucer_collisions(game: &mut Asteroids, ctx: &mut GameCtx) {
+    // Saucer shots break asteroids but score nothing
+    let mut b = 0;
+    while b < game.saucer_bullets.len() {
+        let pos = game.saucer_bullets[b].pos;
+        match game.asteroids.iter().position(|a| asteroid_contains(a, pos)) {
+            Some(i) => {
+                break_asteroid(game, i, ctx);
+                game.saucer_bullets.remove(b);
+            }
+            None => b += 1,
+        }
+    }
+
+    if let Some(saucer) = &game.saucer {
+        let (pos, r) = (saucer.pos, saucer.size.radius());
+        if let Some(b) = game.bullets.iter().position(|b| wrapped_distance(b.pos, pos) < r) {
+            game.bullets.remove(b);
+            game.score += saucer.size.points();
+            destroy_saucer(game, ctx);
+        } else if let Some(i) = game.asteroids.iter().position(|a| wrapped_distance(a.pos, pos) < a.size.radius() + r) {
+            break_asteroid(game, i, ctx);
+            destroy_saucer(game, ctx);
+        }
+    }
+
+    let ship = &game.ship;
+    if game.respawn_timer > 0.0 || game.game_over || ship.in_hyperspace() || ship.invulnerable_timer > 0.0 {
+        return;
+    }
+    let ship = game.ship.pos;
+    if let Some(b) = game.saucer_bullets.iter().position(|b| wrapped_distance(b.pos, ship) < SHIP_RADIUS) {
+        // The shield soaks up the shot
+        game.saucer_bullets.remove(b);
+        if !game.ship.shielded {
+            destroy_ship(game, ctx);
+        }
+    } else if !game.ship.shielded
+        && let Some(saucer) = &game.saucer
+        && wrapped_distance(saucer.pos, ship) < saucer.size.radius() + SHIP_RADIUS
+    {
+        // Ramming costs a life but still pays out
+        game.score += saucer.size.points();
+        destroy_saucer(game, ctx);
+        destroy_ship(game, ctx);
+    }
+}
+
+fn destroy_saucer(game: &mut Asteroids, ctx: &mut GameCtx) {
+    if let Some(saucer) = game.saucer.take() {
+        emit(ctx.audio, SoundEvent::EnemyDestroyed);
+        spawn_explosion(&mut game.particles, saucer.pos, ctx.rng);
+    }
+    game.saucer_timer = spawn_delay(game.level);
+}
+
@@ -3,6 +3,8 @@
 use glam::Vec2;
 use vectorcade_shared::{GameRng, wrap_position};
 
+use crate::geometry::wrapped_lerp;
+
 /// Size variants for asteroids.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum AsteroidSize {
@@ -38,11 +40,15 @@ impl AsteroidSize {
 }
 
 /// An asteroid floating in space.
+#[derive(Clone)]
 pub struct Asteroid {
     pub pos: Vec2,
     pub vel: Vec2,
     pub size: AsteroidSize,
     pub rot: f32,
+    /// Position and rotation before the latest step, for drawing.
+    pub prev_pos: Vec2,
+    pub prev_rot: f32,
     pub rot_speed: f32,
     pub shape_seed: u32,
 }
@@ -57,11 +63,15 @@ impl Asteroid {
             _ => Vec2::new(1.0, rng.range_f32(-1.0, 1.0)),
         };
         let angle = rng.range_f32(0.0, std::f32::consts::TAU);
+        let vel = Vec2::new(angle.cos(), angle.sin()) * rng.range_f32(0.1, 0.3);
+        let rot = rng.range_f32(0.0, std::f32::consts::TAU);
         Self {
             pos,
-            vel: Vec2::new(angle.cos(), angle.sin()) * rng.range_f32(0.1, 0.3),
+            vel,
             size: AsteroidSize::Large,
-            rot: rng.range_f32(0.0, std::f32::consts::TAU),
+            rot,
+            prev_pos: pos,
+            prev_rot: rot,
             rot_speed: rng.range_f32(-2.0, 2.0),
             shape_seed: rng.next_u32(),
         }
@@ -70,19 +80,49 @@ impl Asteroid {
     pub fn spawn_split(parent: &Asteroid, rng: &mut dyn GameRng) -> Self {
         let offset_angle = rng.range_f32(0.0, std::f32::consts::TAU);
         let angle = rng.range_f32(0.0, std::f32::consts::TAU);
+        let pos = parent.pos + Vec2::new(offset_angle.cos(), offset_angle.sin()) * 0.05;
+        let rot = rng.range_f32(0.0, std::f32::consts::TAU);
         Self {
-            pos: parent.pos + Vec2::new(offset_angle.cos(), offset_angle.sin()) * 0.05,
+            pos,
             vel: Vec2::new(angle.cos(), angle.sin()) * parent.vel.length() * 1.2,
             size: parent.size.split().unwrap_or(AsteroidSize::Small),
-            rot: rng.range_f32(0.0, std::f32::consts::TAU),
+            rot,
+            prev_pos: pos,
+            prev_rot: rot,
             rot_speed: rng.range_f32(-3.0, 3.0),
             shape_seed: rng.next_u32(),
         }
     }
 
+    pub const SIDES: usize = 8;
+
+    /// The asteroid as drawn `alpha` (0..1) of the way through the latest step.
+    pub fn interpolated(&self, alpha: f32) -> Self {
+        Self {
+            pos: wrapped_lerp(self.prev_pos, self.pos, alpha),
+            rot: self.prev_rot + (self.rot - self.prev_rot) * alpha,
+            ..self.clone()
+        }
+    }
+
     pub fn update(&mut self, dt: f32) {
         self.pos += self.vel * dt;
         self.pos = wrap_position(self.pos);
         self.rot += self.rot_speed * dt;
     }
+
+    /// The jagged outline in world space, as drawn and as collided with.
+    /// Vertex radii come from `shape_seed`, so the outline can be concave.
+    pub fn outline(&self) -> Vec<Vec2> {
+        let radius = self.size.radius();
+        let mut seed = self.shape_seed;
+        (0..Self::SIDES)
+            .map(|i| {
+                let angle = self.rot + (i as f32 / Self::SIDES as f32) * std::f32::consts::TAU;
+                seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+                let r = radius * (0.7 + 0.3 * ((seed % 100) as f32 / 100.0));
+                self.pos + Vec2::new(angle.cos(), angle.sin()) * r
+            })
+            .collect()
+    }
 }
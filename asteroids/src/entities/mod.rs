@@ -2,8 +2,10 @@
 
 mod actors;
 mod projectiles;
+mod saucer;
 mod ship;
 
-pub use actors::Asteroid;
+pub use actors::{Asteroid, AsteroidSize};
 pub use projectiles::{Bullet, Particle};
+pub use saucer::{Saucer, SaucerSize};
 pub use ship::Ship;
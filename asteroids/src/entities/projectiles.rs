@@ -3,9 +3,14 @@
 use glam::Vec2;
 use vectorcade_shared::wrap_position;
 
+use crate::geometry::wrapped_lerp;
+
 /// A bullet fired by the ship.
+#[derive(Clone)]
 pub struct Bullet {
     pub pos: Vec2,
+    /// Position before the latest step, for drawing.
+    pub prev_pos: Vec2,
     pub vel: Vec2,
     pub lifetime: f32,
 }
@@ -17,11 +22,17 @@ impl Bullet {
     pub fn new(pos: Vec2, direction: Vec2) -> Self {
         Self {
             pos,
+            prev_pos: pos,
             vel: direction * Self::SPEED,
             lifetime: Self::LIFETIME,
         }
     }
 
+    /// The bullet as drawn `alpha` (0..1) of the way through the latest step.
+    pub fn interpolated(&self, alpha: f32) -> Self {
+        Self { pos: wrapped_lerp(self.prev_pos, self.pos, alpha), ..self.clone() }
+    }
+
     pub fn update(&mut self, dt: f32) {
         self.pos += self.vel * dt;
         self.pos = wrap_position(self.pos);
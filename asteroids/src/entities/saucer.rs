@@ -0,0 +1,103 @@
+//! Flying saucer entity for Asteroids.
+
+use glam::Vec2;
+use vectorcade_shared::GameRng;
+
+/// Saucer variants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SaucerSize {
+    /// Big, slow and fires at random.
+    Large,
+    /// Small, quick and aims at the ship.
+    Small,
+}
+
+impl SaucerSize {
+    pub fn radius(self) -> f32 {
+        match self {
+            Self::Large => 0.05,
+            Self::Small => 0.03,
+        }
+    }
+
+    pub fn points(self) -> u32 {
+        match self {
+            Self::Large => 200,
+            Self::Small => 1000,
+        }
+    }
+
+    pub fn speed(self) -> f32 {
+        match self {
+            Self::Large => 0.25,
+            Self::Small => 0.4,
+        }
+    }
+
+    /// Seconds between shots.
+    pub fn fire_interval(self) -> f32 {
+        match self {
+            Self::Large => 1.2,
+            Self::Small => 0.9,
+        }
+    }
+}
+
+/// A saucer crossing the screen from one side to the other.
+#[derive(Clone)]
+pub struct Saucer {
+    pub pos: Vec2,
+    /// Position before the latest step, for drawing.
+    pub prev_pos: Vec2,
+    pub vel: Vec2,
+    pub size: SaucerSize,
+    pub fire_timer: f32,
+    /// Seconds until the saucer picks a new vertical heading.
+    pub turn_timer: f32,
+}
+
+impl Saucer {
+    const TURN_INTERVAL: f32 = 1.5;
+
+    /// Enter from a random side edge, heading for the opposite one.
+    pub fn spawn(size: SaucerSize, rng: &mut dyn GameRng) -> Self {
+        let dir = if rng.pick_index(2) == Some(0) { 1.0 } else { -1.0 };
+        let pos = Vec2::new(-dir, rng.range_f32(-0.8, 0.8));
+        Self {
+            pos,
+            prev_pos: pos,
+            vel: Vec2::new(dir * size.speed(), 0.0),
+            size,
+            fire_timer: size.fire_interval(),
+            turn_timer: Self::TURN_INTERVAL,
+        }
+    }
+
+    /// The saucer as drawn `alpha` (0..1) of the way through the latest step.
+    pub fn interpolated(&self, alpha: f32) -> Self {
+        Self { pos: self.prev_pos.lerp(self.pos, alpha), ..self.clone() }
+    }
+
+    /// Move the saucer; returns `false` once it has left the screen.
+    pub fn update(&mut self, rng: &mut dyn GameRng, dt: f32) -> bool {
+        self.turn_timer -= dt;
+        if self.turn_timer <= 0.0 {
+            self.turn_timer = Self::TURN_INTERVAL;
+            let heading = rng.pick_index(3).unwrap_or(0) as f32 - 1.0;
+            self.vel.y = heading * self.size.speed() * 0.6;
+        }
+        self.pos += self.vel * dt;
+        // Wraps vertically but leaves through the far side, taking the
+        // drawing's starting point along so it doesn't slide back across
+        let wrap = if self.pos.y > 1.0 {
+            -2.0
+        } else if self.pos.y < -1.0 {
+            2.0
+        } else {
+            0.0
+        };
+        self.pos.y += wrap;
+        self.prev_pos.y += wrap;
+        self.pos.x.abs() <= 1.0 + self.size.radius()
+    }
+}
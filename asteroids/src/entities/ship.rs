@@ -2,17 +2,70 @@
 
 use glam::Vec2;
 
+use crate::geometry::wrapped_lerp;
+
 /// The player's ship.
+#[derive(Clone)]
 pub struct Ship {
     pub pos: Vec2,
     pub vel: Vec2,
     pub angle: f32,
+    /// Position and angle before the latest step, blended towards the
+    /// current ones when drawing.
+    pub prev_pos: Vec2,
+    pub prev_angle: f32,
     pub thrusting: bool,
+    /// Seconds until the gun can fire again.
+    pub fire_timer: f32,
+    /// Seconds left in hyperspace; the ship is gone while positive.
+    pub hyperspace_timer: f32,
+    pub shielded: bool,
+    /// Remaining shield charge, 0..=1.
+    pub shield_energy: f32,
+    /// Seconds of post-respawn grace; the ship blinks and cannot be hit.
+    pub invulnerable_timer: f32,
 }
 
 impl Ship {
     pub fn new() -> Self {
-        Self { pos: Vec2::ZERO, vel: Vec2::ZERO, angle: 0.0, thrusting: false }
+        Self {
+            pos: Vec2::ZERO,
+            vel: Vec2::ZERO,
+            angle: 0.0,
+            prev_pos: Vec2::ZERO,
+            prev_angle: 0.0,
+            thrusting: false,
+            fire_timer: 0.0,
+            hyperspace_timer: 0.0,
+            shielded: false,
+            shield_energy: 1.0,
+            invulnerable_timer: 0.0,
+        }
+    }
+
+    /// The ship as drawn `alpha` (0..1) of the way through the latest step.
+    pub fn interpolated(&self, alpha: f32) -> Self {
+        Self {
+            pos: wrapped_lerp(self.prev_pos, self.pos, alpha),
+            angle: self.prev_angle + (self.angle - self.prev_angle) * alpha,
+            ..self.clone()
+        }
+    }
+
+    /// Hull outline in world space: nose, then the two rear corners.
+    pub fn hull(&self) -> [Vec2; 3] {
+        let (s, c) = (self.angle.sin(), self.angle.cos());
+        let rotate = |v: Vec2| self.pos + Vec2::new(v.x * c - v.y * s, v.x * s + v.y * c);
+        [rotate(Vec2::new(0.04, 0.0)), rotate(Vec2::new(-0.025, 0.02)), rotate(Vec2::new(-0.025, -0.02))]
+    }
+
+    /// Whether collisions can currently destroy the ship.
+    pub fn can_be_hit(&self) -> bool {
+        !self.in_hyperspace() && !self.shielded && self.invulnerable_timer <= 0.0
+    }
+
+    pub fn in_hyperspace(&self) -> bool {
+        self.hyperspace_timer > 0.0
     }
 
     pub fn direction(&self) -> Vec2 {
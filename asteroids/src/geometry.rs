@@ -0,0 +1,95 @@
+//! Wrap-aware geometry for the toroidal playfield.
+
+use glam::Vec2;
+use vectorcade_shared::wrap_position;
+
+/// Width and height of the playfield, which spans -1..1 on both axes.
+const SPAN: f32 = 2.0;
+
+/// Shortest offset from `a` to `b`, allowing for wrap-around.
+pub fn wrapped_delta(a: Vec2, b: Vec2) -> Vec2 {
+    let wrap = |d: f32| d - SPAN * (d / SPAN).round();
+    let d = b - a;
+    Vec2::new(wrap(d.x), wrap(d.y))
+}
+
+/// The point `t` of the way from `a` to `b` the short way round, wrapped
+/// back onto the playfield.
+pub fn wrapped_lerp(a: Vec2, b: Vec2, t: f32) -> Vec2 {
+    wrap_position(a + wrapped_delta(a, b) * t)
+}
+
+/// Distance between `a` and `b` the short way round the playfield.
+pub fn wrapped_distance(a: Vec2, b: Vec2) -> f32 {
+    wrapped_delta(a, b).length()
+}
+
+/// Offsets at which a shape of `radius` around `pos` must be drawn so that
+/// any part hanging over an edge shows up on the opposite side. The first
+/// entry is always `Vec2::ZERO` (the shape itself).
+pub fn ghost_offsets(pos: Vec2, radius: f32) -> Vec<Vec2> {
+    let shift = |v: f32| {
+        if v + radius > 1.0 {
+            -SPAN
+        } else if v - radius < -1.0 {
+            SPAN
+        } else {
+            0.0
+        }
+    };
+    let (dx, dy) = (shift(pos.x), shift(pos.y));
+    let mut offsets = vec![Vec2::ZERO];
+    if dx != 0.0 {
+        offsets.push(Vec2::new(dx, 0.0));
+    }
+    if dy != 0.0 {
+        offsets.push(Vec2::new(0.0, dy));
+    }
+    if dx != 0.0 && dy != 0.0 {
+        offsets.push(Vec2::new(dx, dy));
+    }
+    offsets
+}
+
+/// Even-odd point-in-polygon test; works for concave outlines.
+pub fn point_in_polygon(p: Vec2, poly: &[Vec2]) -> bool {
+    let mut inside = false;
+    let mut j = poly.len().wrapping_sub(1);
+    for (i, &a) in poly.iter().enumerate() {
+        let b = poly[j];
+        if (a.y > p.y) != (b.y > p.y) && p.x < a.x + (p.y - a.y) * (b.x - a.x) / (b.y - a.y) {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+/// Whether two closed polygons (either may be concave) touch or overlap.
+pub fn polygons_overlap(a: &[Vec2], b: &[Vec2]) -> bool {
+    let edges = |poly: &[Vec2]| {
+        let n = poly.len();
+        (0..n).map(move |i| (poly[i], poly[(i + 1) % n])).collect::<Vec<_>>()
+    };
+    let (ea, eb) = (edges(a), edges(b));
+    if ea.iter().any(|&(p, q)| eb.iter().any(|&(r, s)| segments_cross(p, q, r, s))) {
+        return true;
+    }
+    // No edges cross: overlap only if one lies entirely inside the other
+    a.first().is_some_and(|&p| point_in_polygon(p, b)) || b.first().is_some_and(|&p| point_in_polygon(p, a))
+}
+
+fn segments_cross(p: Vec2, q: Vec2, r: Vec2, s: Vec2) -> bool {
+    let d1 = (q - p).perp_dot(r - p);
+    let d2 = (q - p).perp_dot(s - p);
+    let d3 = (s - r).perp_dot(p - r);
+    let d4 = (s - r).perp_dot(q - r);
+    d1 * d2 <= 0.0 && d3 * d4 <= 0.0 && !(d1 == 0.0 && d2 == 0.0)
+}
+
+/// Move `shape` (drawn around `from`) to wherever `from` sits nearest to `to`
+/// on the wrapping playfield, so it can be tested against a shape at `to`.
+pub fn wrap_near(shape: &[Vec2], from: Vec2, to: Vec2) -> Vec<Vec2> {
+    let shift = (to + wrapped_delta(to, from)) - from;
+    shape.iter().map(|p| *p + shift).collect()
+}
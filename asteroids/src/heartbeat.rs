@@ -0,0 +1,36 @@
+//! The background heartbeat that speeds up as a wave drags on.
+
+use arcade_kit::sound::{SoundEvent, emit};
+use vectorcade_shared::game::GameCtx;
+
+use crate::Asteroids;
+
+const SLOWEST: f32 = 1.0;
+const FASTEST: f32 = 0.25;
+/// Seconds into a wave at which time alone has the beat at full speed.
+const RAMP_TIME: f32 = 60.0;
+/// Asteroid count at or above which the field does not speed the beat up.
+const CROWD: f32 = 12.0;
+
+/// Seconds between beats, `wave_time` seconds into a wave with
+/// `asteroids_left` rocks still on screen.
+pub fn beat_interval(wave_time: f32, asteroids_left: usize) -> f32 {
+    let time = (1.0 - wave_time / RAMP_TIME).clamp(0.0, 1.0);
+    let crowd = (asteroids_left as f32 / CROWD).clamp(0.0, 1.0);
+    FASTEST + (SLOWEST - FASTEST) * time * crowd
+}
+
+/// Advance the wave clock and emit a beat when one is due. Silent while
+/// the ship is waiting to respawn.
+pub fn update_heartbeat(game: &mut Asteroids, ctx: &GameCtx, dt: f32) {
+    game.wave_time += dt;
+    if game.respawn_timer > 0.0 {
+        return;
+    }
+    game.beat_timer -= dt;
+    if game.beat_timer <= 0.0 {
+        game.beat_timer = beat_interval(game.wave_time, game.asteroids.len());
+        emit(ctx.audio, if game.beat_high { SoundEvent::HeartbeatHigh } else { SoundEvent::HeartbeatLow });
+        game.beat_high = !game.beat_high;
+    }
+}
@@ -0,0 +1,91 @@
+//! High-score table and arcade-style initials entry.
+
+use vectorcade_shared::input::{InputState, Key};
+
+/// Entries kept in the table.
+pub const TABLE_SIZE: usize = 10;
+
+/// One line of the high-score table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HighScore {
+    /// Three uppercase ASCII letters.
+    pub initials: [u8; 3],
+    pub score: u32,
+}
+
+impl HighScore {
+    pub fn initials_str(&self) -> String {
+        self.initials.iter().map(|&c| c as char).collect()
+    }
+}
+
+/// Where the table lives between games; a shell can implement this to
+/// persist scores. Best score first, at most [`TABLE_SIZE`] entries.
+pub trait HighScoreStore: Send {
+    fn load(&self) -> Vec<HighScore>;
+    fn save(&mut self, table: &[HighScore]);
+}
+
+/// Default store: forgotten when the game is dropped.
+#[derive(Default)]
+pub struct MemoryStore {
+    table: Vec<HighScore>,
+}
+
+impl HighScoreStore for MemoryStore {
+    fn load(&self) -> Vec<HighScore> {
+        self.table.clone()
+    }
+
+    fn save(&mut self, table: &[HighScore]) {
+        self.table = table.to_vec();
+    }
+}
+
+/// Whether `score` earns a place in `table`.
+pub fn qualifies(table: &[HighScore], score: u32) -> bool {
+    score > 0 && (table.len() < TABLE_SIZE || table.last().is_some_and(|last| score > last.score))
+}
+
+/// Add `entry` below any equal scores and drop whatever falls off the end.
+pub fn insert(table: &mut Vec<HighScore>, entry: HighScore) {
+    let at = table.iter().position(|e| entry.score > e.score).unwrap_or(table.len());
+    table.insert(at, entry);
+    table.truncate(TABLE_SIZE);
+}
+
+/// Three-letter initials being dialled in with Left/Right and Fire.
+#[derive(Clone, Debug)]
+pub struct InitialsEntry {
+    pub letters: [u8; 3],
+    /// Letter currently being chosen.
+    pub cursor: usize,
+}
+
+impl Default for InitialsEntry {
+    fn default() -> Self { Self::new() }
+}
+
+impl InitialsEntry {
+    pub fn new() -> Self {
+        Self { letters: [b'A'; 3], cursor: 0 }
+    }
+
+    /// Apply this step's key presses; returns `true` once the last letter is locked in.
+    pub fn update(&mut self, input: &dyn InputState) -> bool {
+        let letter = &mut self.letters[self.cursor];
+        if input.key(Key::Right).went_down {
+            *letter = if *letter == b'Z' { b'A' } else { *letter + 1 };
+        }
+        if input.key(Key::Left).went_down {
+            *letter = if *letter == b'A' { b'Z' } else { *letter - 1 };
+        }
+        if input.key(Key::Space).went_down {
+            if self.cursor == self.letters.len() - 1 {
+                return true;
+            }
+            self.cursor += 1;
+        }
+        false
+    }
+}
@@ -1,9 +1,16 @@
 //! Asteroids game implementation.
 
+mod enemies;
 mod entities;
+mod geometry;
+mod heartbeat;
+mod highscore;
 mod physics;
 mod rendering;
 
+use arcade_kit::debris::Debris;
+use arcade_kit::sound::{SoundEvent, emit};
+use arcade_kit::timestep::{FixedStep, FixedUpdate, run_fixed};
 use vectorcade_shared::{
     Rgba,
     draw::DrawCmd,
@@ -12,25 +19,81 @@ use vectorcade_shared::{
     input::Key,
 };
 
-use entities::{Asteroid, Bullet, Particle, Ship};
+pub use enemies::{aim_error, small_chance, spawn_delay};
+pub use entities::{Bullet, Saucer, SaucerSize};
+pub use heartbeat::beat_interval;
+pub use geometry::{ghost_offsets, point_in_polygon, polygons_overlap, wrapped_delta, wrapped_distance};
+pub use highscore::{HighScore, HighScoreStore, InitialsEntry, MemoryStore, TABLE_SIZE};
+
+use entities::{Asteroid, Particle, Ship};
 
 const MAX_ASTEROIDS: usize = 20;
 const MAX_BULLETS: usize = 8;
 const MAX_PARTICLES: usize = 50;
+/// Furthest any part of the ship drawing (shield included) reaches from its centre.
+const SHIP_EXTENT: f32 = 0.06;
+/// Seconds each attract-mode page stays up.
+const ATTRACT_PAGE: f32 = 5.0;
+
+/// Special ability bound to the Down key.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Ability {
+    /// Vanish and reappear at random, with a chance of not surviving.
+    #[default]
+    Hyperspace,
+    /// Hold for protection until the energy meter runs dry.
+    Shield,
+}
+
+/// Per-game options chosen before play starts.
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    pub ability: Ability,
+    /// Points between extra ships; 0 turns extra ships off.
+    pub extra_life_every: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self { ability: Ability::default(), extra_life_every: 10_000 }
+    }
+}
 
 pub struct Asteroids {
+    pub config: Config,
     pub ship: Ship,
     pub asteroids: Vec<Asteroid>,
     pub bullets: Vec<Bullet>,
     pub particles: Vec<Particle>,
+    /// Pieces of the last destroyed ship.
+    pub debris: Vec<Debris>,
+    /// Seconds until those pieces have faded out.
+    pub debris_fade: f32,
+    pub saucer: Option<Saucer>,
+    pub saucer_bullets: Vec<Bullet>,
+    /// Seconds until the next saucer appears.
+    pub saucer_timer: f32,
     pub score: u32,
     pub lives: u8,
     pub level: u8,
+    /// Score at which the next extra ship is awarded.
+    pub next_extra_life: u32,
+    /// Seconds since the current wave began.
+    pub wave_time: f32,
+    /// Seconds until the next heartbeat.
+    pub beat_timer: f32,
+    /// Whether the next heartbeat is the high one.
+    pub beat_high: bool,
     pub font_style: FontStyleId,
     pub game_over: bool,
     pub respawn_timer: f32,
+    /// Initials being entered after a game that made the table.
+    pub entry: Option<InitialsEntry>,
+    pub high_scores: Vec<HighScore>,
+    store: Box<dyn HighScoreStore>,
     pub showing_instructions: bool,
     pub blink_timer: f32,
+    pub clock: FixedStep,
 }
 
 impl Default for Asteroids {
@@ -42,22 +105,113 @@ impl Default for Asteroids {
 impl Asteroids {
     pub fn new() -> Self {
         Self {
+            config: Config::default(),
             ship: Ship::new(),
             asteroids: Vec::with_capacity(MAX_ASTEROIDS),
             bullets: Vec::with_capacity(MAX_BULLETS),
             particles: Vec::with_capacity(MAX_PARTICLES),
+            debris: Vec::new(),
+            debris_fade: 0.0,
+            saucer: None,
+            saucer_bullets: Vec::new(),
+            saucer_timer: enemies::spawn_delay(1),
             score: 0,
             lives: 3,
             level: 1,
+            next_extra_life: Config::default().extra_life_every,
+            wave_time: 0.0,
+            beat_timer: 0.0,
+            beat_high: false,
             font_style: FontStyleId::ATARI,
             game_over: false,
             respawn_timer: 0.0,
+            entry: None,
+            high_scores: Vec::new(),
+            store: Box::new(MemoryStore::default()),
             showing_instructions: true,
             blink_timer: 0.0,
+            clock: FixedStep::default(),
+        }
+    }
+
+    pub fn with_config(config: Config) -> Self {
+        Self { config, next_extra_life: config.extra_life_every, ..Self::new() }
+    }
+
+    /// Keep the high-score table in `store` instead of in memory.
+    pub fn with_store(mut self, store: Box<dyn HighScoreStore>) -> Self {
+        self.high_scores = store.load();
+        self.store = store;
+        self
+    }
+
+    /// Clear the field and begin a new game at level 1.
+    fn start_game(&mut self, ctx: &mut GameCtx) {
+        self.ship = Ship::new();
+        self.asteroids.clear();
+        self.bullets.clear();
+        self.particles.clear();
+        self.debris.clear();
+        self.debris_fade = 0.0;
+        self.saucer = None;
+        self.saucer_bullets.clear();
+        self.score = 0;
+        self.lives = 3;
+        self.level = 1;
+        self.next_extra_life = self.config.extra_life_every;
+        self.saucer_timer = enemies::spawn_delay(self.level);
+        self.game_over = false;
+        self.respawn_timer = 0.0;
+        self.entry = None;
+        self.spawn_level_asteroids(ctx);
+    }
+
+    /// Initials entry, then wait for Fire to play again.
+    fn update_game_over(&mut self, ctx: &mut GameCtx, dt: f32) {
+        self.blink_timer += dt;
+        if let Some(entry) = &mut self.entry {
+            if entry.update(ctx.input) {
+                let initials = entry.letters;
+                highscore::insert(&mut self.high_scores, HighScore { initials, score: self.score });
+                self.store.save(&self.high_scores);
+                self.entry = None;
+            }
+        } else if ctx.input.key(Key::Space).went_down {
+            self.start_game(ctx);
+        }
+    }
+
+    /// Award a ship for every threshold the score has passed.
+    fn award_extra_lives(&mut self, ctx: &GameCtx) {
+        let every = self.config.extra_life_every;
+        while every > 0 && self.score >= self.next_extra_life {
+            self.lives = self.lives.saturating_add(1);
+            self.next_extra_life += every;
+            emit(ctx.audio, SoundEvent::ExtraLife);
+        }
+    }
+
+    /// Note where everything is before a step, for `render` to blend from.
+    /// Screens that don't move anything then draw it still.
+    fn hold_positions(&mut self) {
+        self.ship.prev_pos = self.ship.pos;
+        self.ship.prev_angle = self.ship.angle;
+        for a in &mut self.asteroids {
+            a.prev_pos = a.pos;
+            a.prev_rot = a.rot;
+        }
+        for b in self.bullets.iter_mut().chain(&mut self.saucer_bullets) {
+            b.prev_pos = b.pos;
+        }
+        if let Some(s) = &mut self.saucer {
+            s.prev_pos = s.pos;
         }
     }
 
     fn spawn_level_asteroids(&mut self, ctx: &mut GameCtx) {
+        self.wave_time = 0.0;
+        self.beat_timer = 0.0;
+        self.beat_high = false;
         let count = 3 + self.level as usize;
         for _ in 0..count.min(MAX_ASTEROIDS) {
             self.asteroids.push(Asteroid::spawn_random(ctx.rng));
@@ -74,21 +228,57 @@ impl Game for Asteroids {
     }
 
     fn reset(&mut self, ctx: &mut GameCtx) {
-        self.ship = Ship::new();
-        self.asteroids.clear();
-        self.bullets.clear();
-        self.particles.clear();
-        self.score = 0;
-        self.lives = 3;
-        self.level = 1;
-        self.game_over = false;
-        self.respawn_timer = 0.0;
+        self.start_game(ctx);
         self.showing_instructions = true;
         self.blink_timer = 0.0;
-        self.spawn_level_asteroids(ctx);
+        self.clock.reset();
     }
 
     fn update(&mut self, ctx: &mut GameCtx, dt: f32) {
+        run_fixed(self, ctx, dt);
+    }
+
+    fn render(&mut self, _ctx: &mut GameCtx, out: &mut Vec<DrawCmd>) {
+        out.push(DrawCmd::Clear { color: Rgba::BLACK });
+        if self.showing_instructions {
+            // Attract mode alternates the instructions with the table
+            if !self.high_scores.is_empty() && (self.blink_timer / ATTRACT_PAGE) as u32 % 2 == 1 {
+                rendering::render_high_scores(out, &self.high_scores, self.font_style, self.blink_timer);
+            } else {
+                rendering::render_instructions(out, self.font_style, self.blink_timer, self.config.ability);
+            }
+            return;
+        }
+        // Moving things are drawn part way between their last two steps
+        let alpha = self.clock.alpha();
+        let blink_off = self.ship.invulnerable_timer > 0.0 && (self.ship.invulnerable_timer * 8.0) as u32 % 2 == 1;
+        if !self.game_over && self.respawn_timer <= 0.0 && !self.ship.in_hyperspace() && !blink_off {
+            let ship = self.ship.interpolated(alpha);
+            rendering::render_wrapped(out, ship.pos, SHIP_EXTENT, |out| rendering::render_ship(out, &ship));
+        }
+        let fade = physics::debris_alpha(self.debris_fade);
+        for piece in &self.debris { rendering::render_debris(out, piece, fade); }
+        for asteroid in self.asteroids.iter().map(|a| a.interpolated(alpha)) {
+            rendering::render_wrapped(out, asteroid.pos, asteroid.size.radius(), |out| rendering::render_asteroid(out, &asteroid));
+        }
+        if let Some(saucer) = &self.saucer { rendering::render_saucer(out, &saucer.interpolated(alpha)); }
+        for bullet in self.bullets.iter().chain(&self.saucer_bullets) { rendering::render_bullet(out, &bullet.interpolated(alpha)); }
+        for particle in &self.particles { rendering::render_particle(out, particle); }
+        let shield = (self.config.ability == Ability::Shield).then_some(self.ship.shield_energy);
+        rendering::render_hud(out, self.score, self.lives, shield, self.font_style);
+        if let Some(entry) = &self.entry {
+            rendering::render_initials_entry(out, entry, self.score, self.font_style, self.blink_timer);
+        } else if self.game_over {
+            rendering::render_game_over(out, self.font_style, self.blink_timer);
+        }
+    }
+}
+
+impl FixedUpdate for Asteroids {
+    fn clock(&mut self) -> &mut FixedStep { &mut self.clock }
+
+    fn fixed_update(&mut self, ctx: &mut GameCtx, dt: f32) {
+        self.hold_positions();
         if self.showing_instructions {
             self.blink_timer += dt;
             if ctx.input.key(Key::Space).went_down {
@@ -96,32 +286,31 @@ impl Game for Asteroids {
             }
             return;
         }
-        if self.game_over { return; }
-        physics::update_ship(&mut self.ship, ctx, dt);
+        if self.game_over {
+            self.update_game_over(ctx, dt);
+            return;
+        }
+        // A wrecked ship can't jump or raise its shield
+        if self.respawn_timer <= 0.0 {
+            physics::handle_ability(self, ctx, dt);
+            if self.game_over { return; }
+        }
+        let flying = !self.ship.in_hyperspace() && self.respawn_timer <= 0.0;
+        if flying { physics::update_ship(&mut self.ship, ctx, dt); }
         physics::update_bullets(&mut self.bullets, dt);
+        physics::update_bullets(&mut self.saucer_bullets, dt);
         physics::update_asteroids(&mut self.asteroids, dt);
         physics::update_particles(&mut self.particles, dt);
-        physics::handle_shooting(&self.ship, &mut self.bullets, ctx, dt);
-        physics::handle_collisions(self, ctx);
+        physics::update_debris(&mut self.debris, &mut self.debris_fade, dt);
+        if flying { physics::handle_shooting(&mut self.ship, &mut self.bullets, ctx, dt); }
+        enemies::update_saucer(self, ctx, dt);
+        physics::handle_collisions(self, ctx, dt);
+        enemies::handle_saucer_collisions(self, ctx);
+        if !self.game_over { self.award_extra_lives(ctx); }
+        heartbeat::update_heartbeat(self, ctx, dt);
         if self.asteroids.is_empty() {
             self.level += 1;
             self.spawn_level_asteroids(ctx);
         }
     }
-
-    fn render(&mut self, _ctx: &mut GameCtx, out: &mut Vec<DrawCmd>) {
-        out.push(DrawCmd::Clear { color: Rgba::BLACK });
-        if self.showing_instructions {
-            rendering::render_instructions(out, self.font_style, self.blink_timer);
-            return;
-        }
-        if !self.game_over && self.respawn_timer <= 0.0 {
-            rendering::render_ship(out, &self.ship);
-        }
-        for asteroid in &self.asteroids { rendering::render_asteroid(out, asteroid); }
-        for bullet in &self.bullets { rendering::render_bullet(out, bullet); }
-        for particle in &self.particles { rendering::render_particle(out, particle); }
-        rendering::render_hud(out, self.score, self.lives, self.font_style);
-        if self.game_over { rendering::render_game_over(out, self.font_style); }
-    }
 }
@@ -1,17 +1,36 @@
 //! Physics and collision handling for Asteroids.
 
+use arcade_kit::debris::Debris;
+use arcade_kit::sound::{Size, SoundEvent, emit};
 use glam::Vec2;
 use vectorcade_shared::{GameRng, game::GameCtx, input::Key, wrap_position};
 
-use crate::entities::{Asteroid, Bullet, Particle, Ship};
-use crate::{Asteroids, MAX_ASTEROIDS, MAX_BULLETS, MAX_PARTICLES};
+use crate::entities::{Asteroid, AsteroidSize, Bullet, Particle, Ship};
+use crate::geometry::{point_in_polygon, polygons_overlap, wrap_near, wrapped_delta, wrapped_distance};
+use crate::highscore::{self, InitialsEntry};
+use crate::{Ability, Asteroids, MAX_ASTEROIDS, MAX_BULLETS, MAX_PARTICLES};
 
 const ROTATION_SPEED: f32 = 4.0;
 const THRUST_ACCEL: f32 = 1.0;
 const MAX_SPEED: f32 = 0.8;
 const FRICTION: f32 = 0.5;
 const FIRE_COOLDOWN: f32 = 0.25;
-const SHIP_RADIUS: f32 = 0.03;
+pub(crate) const SHIP_RADIUS: f32 = 0.03;
+/// Distance from the ship's centre to its nose, the furthest hull point.
+const SHIP_REACH: f32 = 0.04;
+const WRECK_TIME: f32 = 2.0;
+/// Seconds the pieces of a wrecked ship take to fade out.
+const DEBRIS_LIFETIME: f32 = 2.0;
+/// Respawn waits until no asteroid is within this distance of the centre.
+const SAFE_RADIUS: f32 = 0.25;
+/// How often a blocked respawn checks the centre again.
+const RESPAWN_RETRY: f32 = 0.1;
+const INVULNERABLE_TIME: f32 = 3.0;
+const HYPERSPACE_TIME: f32 = 0.6;
+/// Chance that re-entry from hyperspace destroys the ship.
+const HYPERSPACE_FAILURE: f32 = 0.1;
+/// Shield charge used per second; a full shield lasts 2.5 s.
+const SHIELD_DRAIN: f32 = 0.4;
 
 pub fn update_ship(ship: &mut Ship, ctx: &GameCtx, dt: f32) {
     if ctx.input.key(Key::Left).is_down {
@@ -22,6 +41,9 @@ pub fn update_ship(ship: &mut Ship, ctx: &GameCtx, dt: f32) {
     }
 
     let thrust = ctx.input.key(Key::Up).is_down || ctx.input.key(Key::W).is_down;
+    if thrust != ship.thrusting {
+        emit(ctx.audio, if thrust { SoundEvent::ThrustStart } else { SoundEvent::ThrustStop });
+    }
     ship.thrusting = thrust;
     if thrust {
         ship.vel += ship.direction() * THRUST_ACCEL * dt;
@@ -35,6 +57,42 @@ pub fn update_ship(ship: &mut Ship, ctx: &GameCtx, dt: f32) {
     ship.pos = wrap_position(ship.pos);
 }
 
+/// Drive the configured special ability, bound to the Down key.
+pub fn handle_ability(game: &mut Asteroids, ctx: &mut GameCtx, dt: f32) {
+    let ship = &mut game.ship;
+    if ship.in_hyperspace() {
+        ship.hyperspace_timer -= dt;
+        if !ship.in_hyperspace() {
+            ship.pos = Vec2::new(ctx.rng.range_f32(-0.9, 0.9), ctx.rng.range_f32(-0.9, 0.9));
+            // Don't blend across the jump
+            ship.prev_pos = ship.pos;
+            if ctx.rng.range_f32(0.0, 1.0) < HYPERSPACE_FAILURE {
+                destroy_ship(game, ctx);
+            }
+        }
+        return;
+    }
+    let key = ctx.input.key(Key::Down);
+    match game.config.ability {
+        Ability::Hyperspace => {
+            if key.went_down {
+                if ship.thrusting {
+                    ship.thrusting = false;
+                    emit(ctx.audio, SoundEvent::ThrustStop);
+                }
+                ship.vel = Vec2::ZERO;
+                ship.hyperspace_timer = HYPERSPACE_TIME;
+            }
+        }
+        Ability::Shield => {
+            ship.shielded = key.is_down && ship.shield_energy > 0.0;
+            if ship.shielded {
+                ship.shield_energy = (ship.shield_energy - SHIELD_DRAIN * dt).max(0.0);
+            }
+        }
+    }
+}
+
 pub fn update_bullets(bullets: &mut Vec<Bullet>, dt: f32) {
     for b in bullets.iter_mut() {
         b.update(dt);
@@ -55,69 +113,153 @@ pub fn update_particles(particles: &mut Vec<Particle>, dt: f32) {
     particles.retain(|p| p.is_alive());
 }
 
-static mut FIRE_TIMER: f32 = 0.0;
-
-pub fn handle_shooting(ship: &Ship, bullets: &mut Vec<Bullet>, ctx: &GameCtx, dt: f32) {
-    unsafe {
-        FIRE_TIMER -= dt;
+/// Drift the wreck's pieces round the playfield, clearing them once `fade`
+/// has run out.
+pub fn update_debris(debris: &mut Vec<Debris>, fade: &mut f32, dt: f32) {
+    for d in debris.iter_mut() {
+        d.drift(dt);
+        d.pos = wrap_position(d.pos);
+    }
+    *fade -= dt;
+    if *fade <= 0.0 {
+        debris.clear();
     }
+}
+
+/// Brightness of the wreck's pieces with `fade` seconds left.
+pub fn debris_alpha(fade: f32) -> f32 {
+    (fade / DEBRIS_LIFETIME).clamp(0.0, 1.0)
+}
+
+pub fn handle_shooting(ship: &mut Ship, bullets: &mut Vec<Bullet>, ctx: &GameCtx, dt: f32) {
+    ship.fire_timer -= dt;
     if ctx.input.key(Key::Space).is_down
-        && unsafe { FIRE_TIMER <= 0.0 }
+        && ship.fire_timer <= 0.0
         && bullets.len() < MAX_BULLETS
     {
         bullets.push(Bullet::new(
             ship.pos + ship.direction() * 0.04,
             ship.direction(),
         ));
-        unsafe {
-            FIRE_TIMER = FIRE_COOLDOWN;
-        }
+        ship.fire_timer = FIRE_COOLDOWN;
+        emit(ctx.audio, SoundEvent::BulletFire);
     }
 }
 
-pub fn handle_collisions(game: &mut Asteroids, ctx: &mut GameCtx) {
-    let mut splits: Vec<Asteroid> = Vec::new();
-    game.bullets.retain(|bullet| {
-        for (i, a) in game.asteroids.iter().enumerate() {
-            if (bullet.pos - a.pos).length() < a.size.radius() {
-                game.score += a.size.points();
-                spawn_explosion(&mut game.particles, a.pos, ctx.rng);
-                if a.size.split().is_some() {
-                    splits.push(Asteroid::spawn_split(a, ctx.rng));
-                    splits.push(Asteroid::spawn_split(a, ctx.rng));
-                }
-                game.asteroids.swap_remove(i);
-                return false;
+pub fn handle_collisions(game: &mut Asteroids, ctx: &mut GameCtx, dt: f32) {
+    let mut b = 0;
+    while b < game.bullets.len() {
+        let pos = game.bullets[b].pos;
+        match game.asteroids.iter().position(|a| asteroid_contains(a, pos)) {
+            Some(i) => {
+                game.score += break_asteroid(game, i, ctx);
+                game.bullets.remove(b);
             }
-        }
-        true
-    });
-    for a in splits {
-        if game.asteroids.len() < MAX_ASTEROIDS {
-            game.asteroids.push(a);
+            None => b += 1,
         }
     }
 
     if game.respawn_timer > 0.0 {
-        game.respawn_timer -= 1.0 / 60.0;
+        game.respawn_timer -= dt;
+        if game.respawn_timer <= 0.0 {
+            try_respawn(game);
+        }
         return;
     }
-    for a in &game.asteroids {
-        if (game.ship.pos - a.pos).length() < a.size.radius() + SHIP_RADIUS {
-            game.lives = game.lives.saturating_sub(1);
-            spawn_explosion(&mut game.particles, game.ship.pos, ctx.rng);
-            if game.lives == 0 {
-                game.game_over = true;
-            } else {
-                game.ship = Ship::new();
-                game.respawn_timer = 2.0;
+    if game.ship.invulnerable_timer > 0.0 {
+        game.ship.invulnerable_timer -= dt;
+    }
+    if !game.ship.can_be_hit() {
+        return;
+    }
+    if game.asteroids.iter().any(|a| ship_touches(&game.ship, a)) {
+        destroy_ship(game, ctx);
+    }
+}
+
+/// Whether `point` lies inside the asteroid's outline.
+pub(crate) fn asteroid_contains(a: &Asteroid, point: Vec2) -> bool {
+    wrapped_distance(a.pos, point) < a.size.radius()
+        && point_in_polygon(a.pos + wrapped_delta(a.pos, point), &a.outline())
+}
+
+/// Whether the ship's hull touches the asteroid's outline.
+fn ship_touches(ship: &Ship, a: &Asteroid) -> bool {
+    wrapped_distance(ship.pos, a.pos) < a.size.radius() + SHIP_REACH
+        && polygons_overlap(&wrap_near(&ship.hull(), ship.pos, a.pos), &a.outline())
+}
+
+/// Blow up asteroid `i`, leaving its fragments behind; returns its points.
+pub(crate) fn break_asteroid(game: &mut Asteroids, i: usize, ctx: &mut GameCtx) -> u32 {
+    let a = game.asteroids.swap_remove(i);
+    emit(ctx.audio, explosion_sound(a.size));
+    spawn_explosion(&mut game.particles, a.pos, ctx.rng);
+    if a.size.split().is_some() {
+        for _ in 0..2 {
+            if game.asteroids.len() < MAX_ASTEROIDS {
+                game.asteroids.push(Asteroid::spawn_split(&a, ctx.rng));
             }
-            break;
         }
     }
+    a.size.points()
+}
+
+/// Lose a life, break up the hull and either start the respawn countdown
+/// or end the game.
+pub(crate) fn destroy_ship(game: &mut Asteroids, ctx: &mut GameCtx) {
+    game.lives = game.lives.saturating_sub(1);
+    if game.ship.thrusting {
+        game.ship.thrusting = false;
+        emit(ctx.audio, SoundEvent::ThrustStop);
+    }
+    game.ship.shielded = false;
+    spawn_explosion(&mut game.particles, game.ship.pos, ctx.rng);
+    spawn_wreck(&mut game.debris, &game.ship, ctx.rng);
+    game.debris_fade = DEBRIS_LIFETIME;
+    if game.lives == 0 {
+        game.game_over = true;
+        if highscore::qualifies(&game.high_scores, game.score) {
+            game.entry = Some(InitialsEntry::new());
+        }
+    } else {
+        game.respawn_timer = WRECK_TIME;
+    }
+}
+
+/// Put a fresh ship at the centre if no asteroid or saucer is near it,
+/// otherwise check again shortly.
+fn try_respawn(game: &mut Asteroids) {
+    let clear = |pos: Vec2, radius: f32| wrapped_distance(pos, Vec2::ZERO) > radius + SAFE_RADIUS;
+    let rocks_clear = game.asteroids.iter().all(|a| clear(a.pos, a.size.radius()));
+    let saucer_clear = game.saucer.as_ref().is_none_or(|s| clear(s.pos, s.size.radius()));
+    if rocks_clear && saucer_clear {
+        game.ship = Ship::new();
+        game.ship.invulnerable_timer = INVULNERABLE_TIME;
+    } else {
+        game.respawn_timer = RESPAWN_RETRY;
+    }
+}
+
+/// Split the hull outline into separate drifting, spinning segments.
+fn spawn_wreck(debris: &mut Vec<Debris>, ship: &Ship, rng: &mut dyn GameRng) {
+    let hull = ship.hull();
+    for i in 0..hull.len() {
+        let (a, b) = (hull[i], hull[(i + 1) % hull.len()]);
+        let outward = ((a + b) * 0.5 - ship.pos).normalize_or_zero();
+        let vel = ship.vel * 0.5 + outward * rng.range_f32(0.05, 0.15);
+        debris.push(Debris::from_segment(a, b, vel, rng.range_f32(-4.0, 4.0)));
+    }
+}
+
+fn explosion_sound(size: AsteroidSize) -> SoundEvent {
+    SoundEvent::AsteroidExplode(match size {
+        AsteroidSize::Large => Size::Large,
+        AsteroidSize::Medium => Size::Medium,
+        AsteroidSize::Small => Size::Small,
+    })
 }
 
-fn spawn_explosion(particles: &mut Vec<Particle>, pos: Vec2, rng: &mut dyn GameRng) {
+pub(crate) fn spawn_explosion(particles: &mut Vec<Particle>, pos: Vec2, rng: &mut dyn GameRng) {
     for _ in 0..8 {
         if particles.len() >= MAX_PARTICLES {
             break;
@@ -1,30 +1,55 @@
 //! Rendering functions for Asteroids.
 
+use arcade_kit::debris::Debris;
 use glam::Vec2;
 use vectorcade_shared::{
     Rgba,
-    draw::{DrawCmd, Stroke},
+    draw::{DrawCmd, Stroke, rect_wire},
     font::FontStyleId,
 };
 
-use crate::entities::{Asteroid, Bullet, Particle, Ship};
+use crate::Ability;
+use crate::entities::{Asteroid, Bullet, Particle, Saucer, Ship};
+use crate::geometry::ghost_offsets;
+use crate::highscore::{HighScore, InitialsEntry};
 
 const WHITE: Rgba = Rgba::WHITE;
 
+/// Draw a wrapping shape of `radius` around `pos`, repeating its polylines
+/// on the far side of any screen edge it hangs over.
+pub fn render_wrapped(out: &mut Vec<DrawCmd>, pos: Vec2, radius: f32, draw: impl FnOnce(&mut Vec<DrawCmd>)) {
+    let start = out.len();
+    draw(out);
+    let end = out.len();
+    for offset in ghost_offsets(pos, radius).into_iter().skip(1) {
+        for i in start..end {
+            if let DrawCmd::Polyline { pts, closed, stroke } = &out[i] {
+                let ghost = DrawCmd::Polyline { pts: pts.iter().map(|p| *p + offset).collect(), closed: *closed, stroke: *stroke };
+                out.push(ghost);
+            }
+        }
+    }
+}
+
 pub fn render_ship(out: &mut Vec<DrawCmd>, ship: &Ship) {
     let (s, c) = (ship.angle.sin(), ship.angle.cos());
     let rotate = |v: Vec2| Vec2::new(v.x * c - v.y * s, v.x * s + v.y * c);
-    let pts = vec![
-        ship.pos + rotate(Vec2::new(0.04, 0.0)),
-        ship.pos + rotate(Vec2::new(-0.025, 0.02)),
-        ship.pos + rotate(Vec2::new(-0.025, -0.02)),
-    ];
     out.push(DrawCmd::Polyline {
-        pts,
+        pts: ship.hull().to_vec(),
         closed: true,
         stroke: Stroke::new(WHITE, 2.0),
     });
 
+    if ship.shielded {
+        let pts = (0..12)
+            .map(|i| {
+                let a = i as f32 / 12.0 * std::f32::consts::TAU;
+                ship.pos + Vec2::new(a.cos(), a.sin()) * 0.06
+            })
+            .collect();
+        out.push(DrawCmd::Polyline { pts, closed: true, stroke: Stroke::new(WHITE.with_a(0.7), 1.5) });
+    }
+
     if ship.thrusting {
         let pts = vec![
             ship.pos + rotate(Vec2::new(-0.025, 0.01)),
@@ -40,25 +65,32 @@ pub fn render_ship(out: &mut Vec<DrawCmd>, ship: &Ship) {
 }
 
 pub fn render_asteroid(out: &mut Vec<DrawCmd>, asteroid: &Asteroid) {
-    let (sides, radius) = (8, asteroid.size.radius());
-    let mut pts = Vec::with_capacity(sides);
-    let mut seed = asteroid.shape_seed;
-    for i in 0..sides {
-        let angle = asteroid.rot + (i as f32 / sides as f32) * std::f32::consts::TAU;
-        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
-        let r = radius * (0.7 + 0.3 * ((seed % 100) as f32 / 100.0));
-        pts.push(Vec2::new(
-            asteroid.pos.x + r * angle.cos(),
-            asteroid.pos.y + r * angle.sin(),
-        ));
-    }
     out.push(DrawCmd::Polyline {
-        pts,
+        pts: asteroid.outline(),
         closed: true,
         stroke: Stroke::new(WHITE, 1.5),
     });
 }
 
+pub fn render_saucer(out: &mut Vec<DrawCmd>, saucer: &Saucer) {
+    let r = saucer.size.radius();
+    let p = |x: f32, y: f32| saucer.pos + Vec2::new(x, y) * r;
+    let stroke = Stroke::new(WHITE, 1.5);
+    // Hull, with the rim line across its widest point
+    out.push(DrawCmd::Polyline {
+        pts: vec![p(-1.0, 0.0), p(-0.45, 0.3), p(0.45, 0.3), p(1.0, 0.0), p(0.45, -0.35), p(-0.45, -0.35)],
+        closed: true,
+        stroke,
+    });
+    out.push(DrawCmd::Polyline { pts: vec![p(-1.0, 0.0), p(1.0, 0.0)], closed: false, stroke });
+    // Canopy
+    out.push(DrawCmd::Polyline {
+        pts: vec![p(-0.45, 0.3), p(-0.25, 0.6), p(0.25, 0.6), p(0.45, 0.3)],
+        closed: false,
+        stroke,
+    });
+}
+
 pub fn render_bullet(out: &mut Vec<DrawCmd>, bullet: &Bullet) {
     let size = 0.008;
     out.push(DrawCmd::Polyline {
@@ -79,7 +111,18 @@ pub fn render_particle(out: &mut Vec<DrawCmd>, particle: &Particle) {
     });
 }
 
-pub fn render_hud(out: &mut Vec<DrawCmd>, score: u32, lives: u8, style: FontStyleId) {
+/// A segment of a wrecked ship, drawn at brightness `fade`.
+pub fn render_debris(out: &mut Vec<DrawCmd>, debris: &Debris, fade: f32) {
+    let (a, b) = debris.endpoints();
+    out.push(DrawCmd::Polyline {
+        pts: vec![a, b],
+        closed: false,
+        stroke: Stroke::new(WHITE.with_a(fade), 2.0),
+    });
+}
+
+/// `shield` is the remaining charge when the shield ability is in use.
+pub fn render_hud(out: &mut Vec<DrawCmd>, score: u32, lives: u8, shield: Option<f32>, style: FontStyleId) {
     out.push(DrawCmd::Text {
         pos: Vec2::new(-0.9, 0.85),
         text: format!("{:05}", score),
@@ -99,9 +142,20 @@ pub fn render_hud(out: &mut Vec<DrawCmd>, score: u32, lives: u8, style: FontStyl
             stroke: Stroke::new(WHITE, 2.0),
         });
     }
+    if let Some(energy) = shield {
+        let (x0, x1, y) = (-0.9, -0.6, 0.62);
+        out.push(rect_wire(Vec2::new(x0, y - 0.015), Vec2::new(x1, y + 0.015), Stroke::new(WHITE, 1.0)));
+        if energy > 0.0 {
+            out.push(DrawCmd::Polyline {
+                pts: vec![Vec2::new(x0, y), Vec2::new(x0 + (x1 - x0) * energy, y)],
+                closed: false,
+                stroke: Stroke::new(WHITE, 4.0),
+            });
+        }
+    }
 }
 
-pub fn render_game_over(out: &mut Vec<DrawCmd>, style: FontStyleId) {
+pub fn render_game_over(out: &mut Vec<DrawCmd>, style: FontStyleId, blink_timer: f32) {
     out.push(DrawCmd::Text {
         pos: Vec2::new(-0.25, 0.0),
         text: "GAME OVER".to_string(),
@@ -109,15 +163,70 @@ pub fn render_game_over(out: &mut Vec<DrawCmd>, style: FontStyleId) {
         color: WHITE,
         style,
     });
+    if (blink_timer % 1.0) < 0.6 {
+        out.push(DrawCmd::Text {
+            pos: Vec2::new(-0.62, -0.3), text: "PRESS SPACE TO PLAY AGAIN".to_string(), size_px: 40.0, color: WHITE, style,
+        });
+    }
+}
+
+pub fn render_initials_entry(out: &mut Vec<DrawCmd>, entry: &InitialsEntry, score: u32, style: FontStyleId, blink_timer: f32) {
+    let lines = [
+        ("NEW HIGH SCORE".to_string(), -0.48, 0.45, 56.0),
+        (format!("{:05}", score), -0.16, 0.28, 48.0),
+        ("LEFT/RIGHT - CHANGE  SPACE - SELECT".to_string(), -0.85, -0.45, 36.0),
+    ];
+    for (text, x, y, size) in lines {
+        out.push(DrawCmd::Text { pos: Vec2::new(x, y), text, size_px: size, color: WHITE, style });
+    }
+    for (i, &c) in entry.letters.iter().enumerate() {
+        let x = -0.2 + i as f32 * 0.16;
+        out.push(DrawCmd::Text {
+            pos: Vec2::new(x, -0.05), text: (c as char).to_string(), size_px: 80.0, color: WHITE, style,
+        });
+        // Blinking underline under the letter being chosen
+        if i == entry.cursor && (blink_timer % 0.5) < 0.3 {
+            out.push(DrawCmd::Polyline {
+                pts: vec![Vec2::new(x, -0.12), Vec2::new(x + 0.1, -0.12)],
+                closed: false,
+                stroke: Stroke::new(WHITE, 2.0),
+            });
+        }
+    }
+}
+
+pub fn render_high_scores(out: &mut Vec<DrawCmd>, table: &[HighScore], style: FontStyleId, blink_timer: f32) {
+    out.push(DrawCmd::Text {
+        pos: Vec2::new(-0.38, 0.7), text: "HIGH SCORES".to_string(), size_px: 64.0, color: WHITE, style,
+    });
+    for (i, entry) in table.iter().enumerate() {
+        out.push(DrawCmd::Text {
+            pos: Vec2::new(-0.42, 0.5 - i as f32 * 0.1),
+            text: format!("{:2}. {} {:6}", i + 1, entry.initials_str(), entry.score),
+            size_px: 40.0,
+            color: WHITE,
+            style,
+        });
+    }
+    if (blink_timer % 1.0) < 0.6 {
+        out.push(DrawCmd::Text {
+            pos: Vec2::new(-0.70, -0.7), text: "PRESS SPACE TO START".to_string(), size_px: 56.0, color: WHITE, style,
+        });
+    }
 }
 
-pub fn render_instructions(out: &mut Vec<DrawCmd>, style: FontStyleId, blink_timer: f32) {
+pub fn render_instructions(out: &mut Vec<DrawCmd>, style: FontStyleId, blink_timer: f32, ability: Ability) {
+    let ability = match ability {
+        Ability::Hyperspace => "DOWN - HYPERSPACE",
+        Ability::Shield => "DOWN - SHIELD",
+    };
     let lines = [
         ("ASTEROIDS", -0.35, 0.6, 96.0),
-        ("LEFT/RIGHT - ROTATE", -0.70, 0.25, 48.0),
-        ("UP - THRUST", -0.40, 0.05, 48.0),
-        ("SPACE - FIRE", -0.42, -0.15, 48.0),
-        ("DESTROY ALL ASTEROIDS", -0.75, -0.4, 48.0),
+        ("LEFT/RIGHT - ROTATE", -0.70, 0.3, 48.0),
+        ("UP - THRUST", -0.40, 0.14, 48.0),
+        ("SPACE - FIRE", -0.42, -0.02, 48.0),
+        (ability, -0.60, -0.18, 48.0),
+        ("DESTROY ALL ASTEROIDS", -0.75, -0.42, 48.0),
     ];
     for (text, x, y, size) in lines {
         out.push(DrawCmd::Text {
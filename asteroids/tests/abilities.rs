@@ -0,0 +1,103 @@
+use asteroids::{Ability, Asteroids, Config};
+use glam::Vec2;
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+/// Down held; `fresh` reports it as just pressed.
+struct Down {
+    fresh: bool,
+}
+impl InputState for Down {
+    fn key(&self, k: Key) -> Button {
+        match k {
+            Key::Down => Button { is_down: true, went_down: self.fresh, went_up: false },
+            _ => Button::UP,
+        }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+fn step(g: &mut Asteroids, input: &dyn InputState, rng: &mut Xorshift64, dt: f32) {
+    let mut ctx = GameCtx { input, audio: &NoAudio, rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    g.update(&mut ctx, dt);
+}
+
+/// A game in play with no asteroids near the centre.
+fn start(config: Config, rng: &mut Xorshift64) -> Asteroids {
+    let mut g = Asteroids::with_config(config);
+    let mut ctx = GameCtx { input: &Down { fresh: false }, audio: &NoAudio, rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    g.reset(&mut ctx);
+    g.showing_instructions = false;
+    g.saucer_timer = 1000.0;
+    for a in &mut g.asteroids {
+        a.pos = Vec2::new(0.9, 0.9);
+        a.vel = Vec2::ZERO;
+    }
+    g
+}
+
+#[test]
+fn hyperspace_teleports_and_sometimes_kills() {
+    let mut deaths = 0;
+    let mut moved = 0;
+    for seed in 1..=200 {
+        let mut rng = Xorshift64::new(seed);
+        let mut g = start(Config::default(), &mut rng);
+        step(&mut g, &Down { fresh: true }, &mut rng, 1.0 / 60.0);
+        assert!(g.ship.in_hyperspace());
+        for _ in 0..3 {
+            step(&mut g, &Down { fresh: false }, &mut rng, 0.25);
+        }
+        assert!(!g.ship.in_hyperspace());
+        if g.lives < 3 {
+            deaths += 1;
+        } else if g.ship.pos != Vec2::ZERO {
+            moved += 1;
+        }
+    }
+    assert!((5..=50).contains(&deaths), "re-entry failure rate off: {deaths}/200");
+    assert_eq!(moved + deaths, 200);
+}
+
+#[test]
+fn no_hyperspace_while_wrecked() {
+    for seed in 1..=100 {
+        let mut rng = Xorshift64::new(seed);
+        let mut g = start(Config::default(), &mut rng);
+        g.asteroids[0].pos = Vec2::ZERO;
+        step(&mut g, &Down { fresh: false }, &mut rng, 1.0 / 60.0);
+        assert_eq!(g.lives, 2);
+        for a in &mut g.asteroids {
+            a.pos = Vec2::new(0.9, 0.9);
+        }
+        step(&mut g, &Down { fresh: true }, &mut rng, 1.0 / 60.0);
+        assert!(!g.ship.in_hyperspace());
+        for _ in 0..3 {
+            step(&mut g, &Down { fresh: false }, &mut rng, 0.25);
+        }
+        assert_eq!(g.lives, 2, "seed {seed}: lost a second life while wrecked");
+    }
+}
+
+#[test]
+fn shield_protects_until_energy_runs_out() {
+    let mut rng = Xorshift64::new(9);
+    let mut g = start(Config { ability: Ability::Shield, ..Config::default() }, &mut rng);
+    g.asteroids[0].pos = Vec2::ZERO;
+    step(&mut g, &Down { fresh: true }, &mut rng, 1.0 / 60.0);
+    assert!(g.ship.shielded);
+    assert!(!g.ship.in_hyperspace(), "shield config has no hyperspace");
+    for _ in 0..9 {
+        step(&mut g, &Down { fresh: false }, &mut rng, 0.25);
+    }
+    assert_eq!(g.lives, 3, "shield held the asteroid off");
+    assert!(g.ship.shield_energy > 0.0 && g.ship.shield_energy < 0.2);
+    step(&mut g, &Down { fresh: false }, &mut rng, 0.25);
+    step(&mut g, &Down { fresh: false }, &mut rng, 0.25);
+    assert!(g.lives < 3, "an empty shield no longer protects");
+}
@@ -1,4 +1,5 @@
 use asteroids::Asteroids;
+use arcade_kit::sound::{SoundEvent, SoundLog};
 use vectorcade_shared::Xorshift64;
 use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
 use vectorcade_shared::input::{Axis, Button, InputState, Key};
@@ -32,3 +33,64 @@ fn asteroids_advances() {
     assert!(initial_asteroids > 0, "should spawn asteroids on reset");
     g.update(&mut ctx, 0.016);
 }
+
+/// Holds fire and thrust down every frame.
+struct FireAndThrust;
+impl InputState for FireAndThrust {
+    fn key(&self, k: Key) -> Button {
+        match k {
+            Key::Space | Key::Up => Button { is_down: true, went_down: false, went_up: false },
+            _ => Button::UP,
+        }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+#[test]
+fn asteroids_instances_are_independent() {
+    let input = FireAndThrust;
+    let audio = NoAudio;
+    let mut rng_a = Xorshift64::new(777);
+    let mut rng_b = Xorshift64::new(777);
+    let mut a = Asteroids::new();
+    let mut b = Asteroids::new();
+    for (g, rng) in [(&mut a, &mut rng_a), (&mut b, &mut rng_b)] {
+        let mut ctx = GameCtx { input: &input, audio: &audio, rng, screen: ScreenInfo::default(), now_s: 0.0 };
+        g.reset(&mut ctx);
+        g.showing_instructions = false;
+    }
+    // Step the two games interleaved so any shared state would leak between them.
+    for _ in 0..120 {
+        for (g, rng) in [(&mut a, &mut rng_a), (&mut b, &mut rng_b)] {
+            let mut ctx = GameCtx { input: &input, audio: &audio, rng, screen: ScreenInfo::default(), now_s: 0.0 };
+            g.update(&mut ctx, 0.016);
+        }
+        let shots = |g: &Asteroids| g.bullets.iter().map(|b| b.pos).collect::<Vec<_>>();
+        assert_eq!(shots(&a), shots(&b));
+        assert_eq!((a.ship.pos, a.score, a.lives), (b.ship.pos, b.score, b.lives));
+    }
+    assert!(!a.bullets.is_empty() || a.score > 0, "held fire should shoot");
+}
+
+#[test]
+fn asteroids_emits_sounds() {
+    let input = FireAndThrust;
+    let audio = SoundLog::new();
+    let mut rng = Xorshift64::new(12345);
+    let mut ctx = GameCtx {
+        input: &input,
+        audio: &audio,
+        rng: &mut rng,
+        screen: ScreenInfo::default(),
+        now_s: 0.0,
+    };
+    let mut g = Asteroids::new();
+    g.reset(&mut ctx);
+    g.showing_instructions = false;
+    g.update(&mut ctx, 0.016);
+    assert_eq!(audio.count(SoundEvent::ThrustStart), 1);
+    assert_eq!(audio.count(SoundEvent::BulletFire), 1);
+    g.update(&mut ctx, 0.016);
+    assert_eq!(audio.count(SoundEvent::ThrustStart), 1, "thrust loop starts once");
+}
@@ -0,0 +1,85 @@
+use arcade_kit::sound::{SoundEvent, SoundLog};
+use asteroids::{Asteroids, Config, beat_interval};
+use glam::Vec2;
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+/// A game in play with its asteroids parked away from the ship.
+fn game(config: Config, ctx: &mut GameCtx) -> Asteroids {
+    let mut g = Asteroids::with_config(config);
+    g.reset(ctx);
+    g.showing_instructions = false;
+    g.saucer_timer = 1000.0;
+    for a in &mut g.asteroids {
+        a.pos = Vec2::new(0.8, 0.8);
+        a.vel = Vec2::ZERO;
+    }
+    g
+}
+
+#[test]
+fn beat_speeds_up_with_time_and_thinning_field() {
+    assert!(beat_interval(30.0, 12) < beat_interval(0.0, 12));
+    assert!(beat_interval(0.0, 2) < beat_interval(0.0, 12));
+    assert_eq!(beat_interval(0.0, 20), beat_interval(0.0, 12), "a crowded field beats at base tempo");
+    assert_eq!(beat_interval(120.0, 12), beat_interval(500.0, 1));
+}
+
+#[test]
+fn extra_ship_at_each_threshold() {
+    let (input, audio, mut rng) = (NoInput, SoundLog::new(), Xorshift64::new(8));
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = game(Config::default(), &mut ctx);
+    g.score = 9_999;
+    g.update(&mut ctx, 1.0 / 60.0);
+    assert_eq!(g.lives, 3);
+    g.score = 10_000;
+    g.update(&mut ctx, 1.0 / 60.0);
+    assert_eq!(g.lives, 4);
+    assert_eq!(audio.count(SoundEvent::ExtraLife), 1);
+    g.score = 19_000;
+    g.update(&mut ctx, 1.0 / 60.0);
+    assert_eq!(g.lives, 4, "one ship per threshold");
+
+    let mut g = game(Config { extra_life_every: 500, ..Config::default() }, &mut ctx);
+    g.score = 1_600;
+    g.update(&mut ctx, 1.0 / 60.0);
+    assert_eq!(g.lives, 6, "passed 500, 1000 and 1500 at once");
+    assert_eq!(g.next_extra_life, 2_000);
+
+    let mut g = game(Config { extra_life_every: 0, ..Config::default() }, &mut ctx);
+    g.score = 50_000;
+    g.update(&mut ctx, 1.0 / 60.0);
+    assert_eq!(g.lives, 3, "0 disables extra ships");
+}
+
+#[test]
+fn heartbeat_alternates_and_quickens() {
+    let (input, audio, mut rng) = (NoInput, SoundLog::new(), Xorshift64::new(8));
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = game(Config::default(), &mut ctx);
+    let beats = |log: &SoundLog| log.count(SoundEvent::HeartbeatLow) + log.count(SoundEvent::HeartbeatHigh);
+    for _ in 0..40 {
+        g.update(&mut ctx, 0.25);
+    }
+    let early = beats(&audio);
+    let seq: Vec<_> = audio.events().into_iter().filter(|e| matches!(e, SoundEvent::HeartbeatLow | SoundEvent::HeartbeatHigh)).collect();
+    assert_eq!(&seq[..2], &[SoundEvent::HeartbeatLow, SoundEvent::HeartbeatHigh]);
+    audio.clear();
+    for _ in 0..200 {
+        g.update(&mut ctx, 0.25);
+    }
+    audio.clear();
+    for _ in 0..40 {
+        g.update(&mut ctx, 0.25);
+    }
+    assert!(beats(&audio) > early, "late in the wave the beat is faster");
+}
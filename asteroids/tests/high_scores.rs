@@ -0,0 +1,110 @@
+use std::sync::{Arc, Mutex};
+
+use asteroids::{Asteroids, HighScore, HighScoreStore, InitialsEntry, TABLE_SIZE};
+use glam::Vec2;
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+/// Reports `key` (if any) as freshly pressed.
+struct Press(Option<Key>);
+impl InputState for Press {
+    fn key(&self, k: Key) -> Button {
+        if self.0 == Some(k) { Button { is_down: true, went_down: true, went_up: false } } else { Button::UP }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+/// Store shared with the test so saves can be inspected.
+#[derive(Clone, Default)]
+struct SharedStore(Arc<Mutex<Vec<HighScore>>>);
+impl HighScoreStore for SharedStore {
+    fn load(&self) -> Vec<HighScore> { self.0.lock().unwrap().clone() }
+    fn save(&mut self, table: &[HighScore]) { *self.0.lock().unwrap() = table.to_vec(); }
+}
+
+fn hs(initials: &[u8; 3], score: u32) -> HighScore {
+    HighScore { initials: *initials, score }
+}
+
+#[test]
+fn initials_dial_with_left_right_and_fire() {
+    let mut e = InitialsEntry::new();
+    for key in [Key::Right, Key::Right, Key::Space, Key::Left, Key::Space] {
+        assert!(!e.update(&Press(Some(key))));
+    }
+    assert!(e.update(&Press(Some(Key::Space))));
+    assert_eq!(&e.letters, b"CZA");
+}
+
+fn press(g: &mut Asteroids, rng: &mut Xorshift64, key: Option<Key>) {
+    let input = Press(key);
+    let mut ctx = GameCtx { input: &input, audio: &NoAudio, rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    g.update(&mut ctx, 1.0 / 60.0);
+}
+
+/// Lose the last life with `score` on the board.
+fn end_game(g: &mut Asteroids, rng: &mut Xorshift64, score: u32) {
+    let mut ctx = GameCtx { input: &Press(None), audio: &NoAudio, rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    g.reset(&mut ctx);
+    g.showing_instructions = false;
+    g.saucer_timer = 1000.0;
+    g.lives = 1;
+    g.score = score;
+    g.asteroids.truncate(1);
+    g.asteroids[0].pos = Vec2::ZERO;
+    g.update(&mut ctx, 1.0 / 60.0);
+    assert!(g.game_over);
+}
+
+#[test]
+fn qualifying_score_is_entered_and_saved() {
+    let store = SharedStore::default();
+    let seeded: Vec<_> = (1..=TABLE_SIZE as u32).map(|i| hs(b"AAA", i * 100)).rev().collect();
+    *store.0.lock().unwrap() = seeded;
+    let mut g = Asteroids::new().with_store(Box::new(store.clone()));
+    let mut rng = Xorshift64::new(1);
+
+    end_game(&mut g, &mut rng, 50);
+    assert!(g.entry.is_none(), "50 does not beat the bottom score of 100");
+    press(&mut g, &mut rng, Some(Key::Space));
+    assert!(!g.game_over, "fire restarts");
+
+    end_game(&mut g, &mut rng, 550);
+    assert!(g.entry.is_some());
+    for key in [Key::Right, Key::Space, Key::Space, Key::Left, Key::Space] {
+        press(&mut g, &mut rng, Some(key));
+        press(&mut g, &mut rng, None);
+    }
+    assert!(g.entry.is_none());
+    assert!(g.game_over, "table stays up until the next press");
+
+    let saved = store.0.lock().unwrap().clone();
+    assert_eq!(saved.len(), TABLE_SIZE);
+    assert_eq!(saved[5], hs(b"BAZ", 550));
+    assert_eq!(saved.last().unwrap().score, 200, "lowest entry dropped");
+    assert_eq!(g.high_scores, saved);
+
+    press(&mut g, &mut rng, Some(Key::Space));
+    assert!(!g.game_over);
+    assert_eq!(g.score, 0);
+    assert_eq!(g.lives, 3);
+}
+
+#[test]
+fn ties_rank_below_existing_scores() {
+    let store = SharedStore::default();
+    *store.0.lock().unwrap() = vec![hs(b"OLD", 500)];
+    let mut g = Asteroids::new().with_store(Box::new(store.clone()));
+    let mut rng = Xorshift64::new(1);
+    end_game(&mut g, &mut rng, 500);
+    for _ in 0..3 {
+        press(&mut g, &mut rng, Some(Key::Space));
+        press(&mut g, &mut rng, None);
+    }
+    assert_eq!(*store.0.lock().unwrap(), vec![hs(b"OLD", 500), hs(b"AAA", 500)]);
+}
@@ -0,0 +1,103 @@
+use asteroids::{Asteroids, wrapped_delta};
+use glam::Vec2;
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::draw::DrawCmd;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+/// Centre of the bounding box of the first outline drawn with `n` points.
+fn drawn_centre(out: &[DrawCmd], n: usize) -> Vec2 {
+    let pts = out
+        .iter()
+        .find_map(|c| match c {
+            DrawCmd::Polyline { pts, .. } if pts.len() == n => Some(pts),
+            _ => None,
+        })
+        .expect("outline");
+    let (min, max) = pts.iter().fold((Vec2::MAX, Vec2::MIN), |(lo, hi), &p| (lo.min(p), hi.max(p)));
+    (min + max) / 2.0
+}
+
+/// One ship and one non-spinning asteroid, both drifting right.
+fn game(ctx: &mut GameCtx, rock: Vec2) -> Asteroids {
+    let mut g = Asteroids::new();
+    g.reset(ctx);
+    g.showing_instructions = false;
+    g.saucer_timer = 1000.0;
+    g.asteroids.truncate(1);
+    g.asteroids[0].pos = rock;
+    g.asteroids[0].prev_pos = rock;
+    g.asteroids[0].vel = Vec2::new(0.6, 0.0);
+    g.asteroids[0].rot_speed = 0.0;
+    g.ship.vel = Vec2::new(0.3, 0.0);
+    g
+}
+
+#[test]
+fn ship_and_rocks_move_smoothly_between_steps() {
+    let mut rng = Xorshift64::new(1);
+    let mut ctx = GameCtx { input: &NoInput, audio: &NoAudio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = game(&mut ctx, Vec2::new(0.0, 0.6));
+    // 144 Hz display over a 120 Hz simulation: some frames run no step at
+    // all, yet what is drawn should advance on every one of them
+    let (mut ship_x, mut rock_x) = (f32::MIN, f32::MIN);
+    for frame in 0..30 {
+        g.update(&mut ctx, 1.0 / 144.0);
+        let mut out = Vec::new();
+        g.render(&mut ctx, &mut out);
+        let (ship, rock) = (drawn_centre(&out, 3).x, drawn_centre(&out, 8).x);
+        assert!(ship > ship_x, "frame {frame}: ship drawn at {ship}, was {ship_x}");
+        assert!(rock > rock_x, "frame {frame}: rock drawn at {rock}, was {rock_x}");
+        (ship_x, rock_x) = (ship, rock);
+    }
+}
+
+#[test]
+fn blending_takes_the_short_way_across_an_edge() {
+    let mut rng = Xorshift64::new(1);
+    let mut ctx = GameCtx { input: &NoInput, audio: &NoAudio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = game(&mut ctx, Vec2::new(0.95, 0.6));
+    let mut last = None;
+    for frame in 0..30 {
+        g.update(&mut ctx, 1.0 / 144.0);
+        let mut out = Vec::new();
+        g.render(&mut ctx, &mut out);
+        let rock = drawn_centre(&out, 8);
+        if let Some(last) = last {
+            let step = wrapped_delta(last, rock);
+            assert!(step.x > 0.0 && step.x < 0.01, "frame {frame}: rock jumped by {step:?}");
+        }
+        last = Some(rock);
+    }
+}
+
+#[test]
+fn game_over_screen_holds_still() {
+    let mut rng = Xorshift64::new(1);
+    let mut ctx = GameCtx { input: &NoInput, audio: &NoAudio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = game(&mut ctx, Vec2::new(0.0, 0.6));
+    g.update(&mut ctx, 1.0 / 60.0);
+    // The rocks stop where they were when the last ship went
+    g.game_over = true;
+    g.update(&mut ctx, 1.0 / 60.0);
+    let rock = |g: &mut Asteroids, ctx: &mut GameCtx| {
+        let mut out = Vec::new();
+        g.render(ctx, &mut out);
+        drawn_centre(&out, 8)
+    };
+    let still = rock(&mut g, &mut ctx);
+    for frame in 0..10 {
+        g.update(&mut ctx, 1.0 / 144.0);
+        assert_eq!(rock(&mut g, &mut ctx), still, "frame {frame}: rock moved on the game over screen");
+    }
+}
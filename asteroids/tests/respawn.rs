@@ -0,0 +1,78 @@
+use asteroids::Asteroids;
+use glam::Vec2;
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+fn step(g: &mut Asteroids, rng: &mut Xorshift64, dt: f32) {
+    let mut ctx = GameCtx { input: &NoInput, audio: &NoAudio, rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    g.update(&mut ctx, dt);
+}
+
+/// A game in play with one stationary asteroid sitting on the ship.
+fn wrecked_game(rng: &mut Xorshift64) -> Asteroids {
+    let mut g = Asteroids::new();
+    let mut ctx = GameCtx { input: &NoInput, audio: &NoAudio, rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    g.reset(&mut ctx);
+    g.showing_instructions = false;
+    g.saucer_timer = 1000.0;
+    g.asteroids.truncate(1);
+    g.asteroids[0].pos = Vec2::ZERO;
+    g.asteroids[0].vel = Vec2::ZERO;
+    g
+}
+
+#[test]
+fn hull_breaks_into_spinning_segments() {
+    let mut rng = Xorshift64::new(4);
+    let mut g = wrecked_game(&mut rng);
+    step(&mut g, &mut rng, 1.0 / 60.0);
+    assert_eq!(g.lives, 2);
+    assert_eq!(g.debris.len(), 3, "one piece per hull edge");
+    let before: Vec<_> = g.debris.iter().map(|d| (d.pos, d.angle)).collect();
+    step(&mut g, &mut rng, 0.25);
+    for (d, (pos, angle)) in g.debris.iter().zip(before) {
+        assert!(d.pos.distance(Vec2::ZERO) > pos.distance(Vec2::ZERO), "pieces drift apart");
+        assert_ne!(d.angle, angle);
+    }
+    for _ in 0..10 {
+        step(&mut g, &mut rng, 0.25);
+    }
+    assert!(g.debris.is_empty(), "pieces fade out");
+}
+
+#[test]
+fn respawn_waits_for_a_clear_centre() {
+    let mut rng = Xorshift64::new(4);
+    let mut g = wrecked_game(&mut rng);
+    for _ in 0..20 {
+        step(&mut g, &mut rng, 0.25);
+    }
+    assert!(g.respawn_timer > 0.0, "asteroid still blocks the centre");
+    assert_eq!(g.lives, 2);
+
+    g.asteroids[0].pos = Vec2::new(0.8, 0.8);
+    step(&mut g, &mut rng, 0.25);
+    assert!(g.respawn_timer <= 0.0);
+    assert_eq!(g.ship.pos, Vec2::ZERO);
+    assert!(g.ship.invulnerable_timer > 0.0);
+
+    // Grace period: a rock drifting through does no harm
+    g.asteroids[0].pos = Vec2::ZERO;
+    step(&mut g, &mut rng, 0.25);
+    assert_eq!(g.lives, 2);
+    for _ in 0..12 {
+        step(&mut g, &mut rng, 0.25);
+    }
+    assert_eq!(g.lives, 1, "vulnerable again once the grace period ends");
+}
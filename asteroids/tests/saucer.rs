@@ -0,0 +1,95 @@
+use arcade_kit::sound::{SoundEvent, SoundLog};
+use asteroids::{Asteroids, Bullet, Saucer, SaucerSize, aim_error, small_chance, spawn_delay};
+use glam::Vec2;
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+/// A game in play with the field cleared except for one far-off asteroid.
+fn quiet_game(ctx: &mut GameCtx) -> Asteroids {
+    let mut g = Asteroids::new();
+    g.reset(ctx);
+    g.showing_instructions = false;
+    g.asteroids.truncate(1);
+    g.asteroids[0].pos = Vec2::new(-0.8, -0.8);
+    g.asteroids[0].vel = Vec2::ZERO;
+    g
+}
+
+fn saucer(size: SaucerSize, pos: Vec2) -> Saucer {
+    Saucer { pos, prev_pos: pos, vel: Vec2::ZERO, size, fire_timer: 10.0, turn_timer: 10.0 }
+}
+
+#[test]
+fn saucers_get_faster_and_deadlier_with_progress() {
+    assert!(spawn_delay(3) < spawn_delay(1));
+    assert_eq!(spawn_delay(50), spawn_delay(60), "delay has a floor");
+    assert!(small_chance(5, 0) > small_chance(1, 0));
+    assert_eq!(small_chance(1, 40_000), 1.0);
+    assert!(aim_error(20_000) < aim_error(0));
+    assert_eq!(aim_error(40_000), 0.0);
+}
+
+#[test]
+fn saucer_appears_when_timer_expires() {
+    let (input, audio, mut rng) = (NoInput, SoundLog::new(), Xorshift64::new(5));
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = quiet_game(&mut ctx);
+    g.saucer_timer = 0.05;
+    assert!(g.saucer.is_none());
+    g.update(&mut ctx, 0.1);
+    let s = g.saucer.as_ref().expect("saucer spawned");
+    assert!(s.pos.x.abs() > 0.9, "enters from a side edge");
+}
+
+#[test]
+fn shooting_a_saucer_scores() {
+    let (input, audio, mut rng) = (NoInput, SoundLog::new(), Xorshift64::new(5));
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = quiet_game(&mut ctx);
+    g.saucer = Some(saucer(SaucerSize::Small, Vec2::new(0.5, 0.5)));
+    g.bullets.push(Bullet::new(Vec2::new(0.48, 0.5), Vec2::X));
+    g.update(&mut ctx, 1.0 / 60.0);
+    assert!(g.saucer.is_none());
+    assert_eq!(g.score, SaucerSize::Small.points());
+    assert!(audio.contains(SoundEvent::EnemyDestroyed));
+}
+
+#[test]
+fn small_saucer_aims_at_the_ship() {
+    let (input, audio, mut rng) = (NoInput, SoundLog::new(), Xorshift64::new(5));
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = quiet_game(&mut ctx);
+    g.score = 40_000;
+    let mut s = saucer(SaucerSize::Small, Vec2::new(0.6, 0.3));
+    s.fire_timer = 0.0;
+    g.saucer = Some(s);
+    g.update(&mut ctx, 1.0 / 120.0);
+    let shot = g.saucer_bullets.first().expect("saucer fired");
+    let to_ship = (g.ship.pos - shot.pos).normalize();
+    assert!(shot.vel.normalize().dot(to_ship) > 0.999);
+    assert!(audio.contains(SoundEvent::BulletFire));
+}
+
+#[test]
+fn saucer_shots_kill_the_ship_and_break_asteroids() {
+    let (input, audio, mut rng) = (NoInput, SoundLog::new(), Xorshift64::new(5));
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = quiet_game(&mut ctx);
+    g.saucer_bullets.push(Bullet::new(Vec2::new(-0.05, 0.0), Vec2::X));
+    g.update(&mut ctx, 1.0 / 60.0);
+    assert_eq!(g.lives, 2);
+
+    let rock = g.asteroids[0].pos;
+    g.saucer_bullets.push(Bullet::new(rock, Vec2::X));
+    g.update(&mut ctx, 1.0 / 120.0);
+    assert_eq!(g.asteroids.len(), 2, "large asteroid split");
+    assert_eq!(g.score, 0, "saucer kills are not the player's");
+}
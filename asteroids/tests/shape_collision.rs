@@ -0,0 +1,91 @@
+use asteroids::{Asteroids, Bullet, point_in_polygon, polygons_overlap};
+use glam::Vec2;
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+fn v(x: f32, y: f32) -> Vec2 { Vec2::new(x, y) }
+
+#[test]
+fn point_in_concave_polygon() {
+    // A "C" shape opening to the right
+    let c = [v(0.0, 0.0), v(1.0, 0.0), v(1.0, 0.3), v(0.3, 0.3), v(0.3, 0.7), v(1.0, 0.7), v(1.0, 1.0), v(0.0, 1.0)];
+    assert!(point_in_polygon(v(0.1, 0.5), &c));
+    assert!(point_in_polygon(v(0.8, 0.1), &c));
+    assert!(!point_in_polygon(v(0.8, 0.5), &c), "the notch is outside");
+    assert!(!point_in_polygon(v(1.5, 0.5), &c));
+}
+
+#[test]
+fn polygon_overlap_cases() {
+    let square = [v(0.0, 0.0), v(1.0, 0.0), v(1.0, 1.0), v(0.0, 1.0)];
+    let tri = |o: Vec2| [o, o + v(0.2, 0.0), o + v(0.1, 0.2)];
+    assert!(polygons_overlap(&square, &tri(v(0.9, 0.4))), "edges cross");
+    assert!(polygons_overlap(&square, &tri(v(0.4, 0.4))), "fully inside");
+    assert!(polygons_overlap(&tri(v(0.4, 0.4)), &square), "fully containing");
+    assert!(!polygons_overlap(&square, &tri(v(1.1, 0.4))));
+}
+
+fn game(ctx: &mut GameCtx) -> Asteroids {
+    let mut g = Asteroids::new();
+    g.reset(ctx);
+    g.showing_instructions = false;
+    g.saucer_timer = 1000.0;
+    g.asteroids.truncate(1);
+    let a = &mut g.asteroids[0];
+    a.pos = v(0.5, 0.5);
+    a.vel = Vec2::ZERO;
+    a.rot = 0.0;
+    a.rot_speed = 0.0;
+    g
+}
+
+#[test]
+fn bullets_pass_through_gaps_in_the_outline() {
+    let (input, audio, mut rng) = (NoInput, NoAudio, Xorshift64::new(3));
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = game(&mut ctx);
+    let r = g.asteroids[0].size.radius();
+    // Between two vertices every edge is at most r·cos(π/8) from the centre
+    let gap = std::f32::consts::PI / 8.0;
+    let mut shot = Bullet::new(v(0.5, 0.5) + v(gap.cos(), gap.sin()) * r * 0.95, Vec2::X);
+    shot.vel = Vec2::ZERO;
+    g.bullets.push(shot);
+    g.update(&mut ctx, 1.0 / 120.0);
+    assert_eq!(g.score, 0, "inside the circle but outside the rock");
+
+    let mut shot = Bullet::new(v(0.5, 0.5), Vec2::X);
+    shot.vel = Vec2::ZERO;
+    g.bullets = vec![shot];
+    g.update(&mut ctx, 1.0 / 120.0);
+    assert!(g.score > 0);
+}
+
+#[test]
+fn ship_collides_with_the_outline_not_the_circle() {
+    let (input, audio, mut rng) = (NoInput, NoAudio, Xorshift64::new(3));
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = game(&mut ctx);
+    let r = g.asteroids[0].size.radius();
+    let gap = std::f32::consts::PI / 8.0;
+    let dir = v(gap.cos(), gap.sin());
+    // Tail toward the rock, well inside the old circle test but in a gap
+    g.ship.angle = gap;
+    g.ship.pos = v(0.5, 0.5) + dir * (r * 0.95 + 0.025);
+    g.update(&mut ctx, 1.0 / 120.0);
+    assert_eq!(g.lives, 3);
+
+    g.ship.pos = v(0.5, 0.5) + dir * (r * 0.5 + 0.025);
+    g.update(&mut ctx, 1.0 / 120.0);
+    assert_eq!(g.lives, 2);
+}
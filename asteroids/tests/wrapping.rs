@@ -0,0 +1,72 @@
+use asteroids::{Asteroids, ghost_offsets, wrapped_delta, wrapped_distance};
+use glam::Vec2;
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::draw::DrawCmd;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+#[test]
+fn distance_takes_the_short_way_round() {
+    let (a, b) = (Vec2::new(0.95, 0.0), Vec2::new(-0.95, 0.0));
+    assert!((wrapped_distance(a, b) - 0.1).abs() < 1e-5);
+    assert!((wrapped_delta(a, b) - Vec2::new(0.1, 0.0)).length() < 1e-5);
+    let (c, d) = (Vec2::new(-0.97, -0.97), Vec2::new(0.97, 0.97));
+    assert!((wrapped_distance(c, d) - 0.06f32.hypot(0.06)).abs() < 1e-5);
+    assert!((wrapped_distance(Vec2::ZERO, Vec2::new(0.5, 0.0)) - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn ghosts_only_for_shapes_over_an_edge() {
+    assert_eq!(ghost_offsets(Vec2::ZERO, 0.1), vec![Vec2::ZERO]);
+    assert_eq!(ghost_offsets(Vec2::new(0.95, 0.0), 0.1), vec![Vec2::ZERO, Vec2::new(-2.0, 0.0)]);
+    assert_eq!(ghost_offsets(Vec2::new(-0.95, 0.95), 0.1).len(), 4);
+}
+
+fn game(ctx: &mut GameCtx) -> Asteroids {
+    let mut g = Asteroids::new();
+    g.reset(ctx);
+    g.showing_instructions = false;
+    g.saucer_timer = 1000.0;
+    g.asteroids.truncate(1);
+    g.asteroids[0].vel = Vec2::ZERO;
+    g
+}
+
+#[test]
+fn asteroid_across_the_edge_hits_the_ship() {
+    let (input, audio, mut rng) = (NoInput, NoAudio, Xorshift64::new(2));
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = game(&mut ctx);
+    g.asteroids[0].pos = Vec2::new(0.97, 0.0);
+    g.ship.pos = Vec2::new(-0.98, 0.0);
+    g.update(&mut ctx, 1.0 / 60.0);
+    assert_eq!(g.lives, 2);
+}
+
+#[test]
+fn asteroid_over_the_edge_is_drawn_twice() {
+    let (input, audio, mut rng) = (NoInput, NoAudio, Xorshift64::new(2));
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = game(&mut ctx);
+    let polylines = |g: &mut Asteroids, ctx: &mut GameCtx| {
+        let mut out = Vec::new();
+        g.render(ctx, &mut out);
+        out.iter().filter(|c| matches!(c, DrawCmd::Polyline { .. })).count()
+    };
+    // Place the rock outright, with no motion for the drawing to blend over
+    let place = |g: &mut Asteroids, pos: Vec2| (g.asteroids[0].pos, g.asteroids[0].prev_pos) = (pos, pos);
+    place(&mut g, Vec2::new(0.5, 0.5));
+    let inside = polylines(&mut g, &mut ctx);
+    place(&mut g, Vec2::new(0.95, 0.5));
+    assert_eq!(polylines(&mut g, &mut ctx), inside + 1);
+}
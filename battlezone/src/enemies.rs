@@ -1,6 +1,17 @@
 //! Enemy tanks and other hostile units.
 
+use arcade_kit::sound::{SoundEvent, emit};
 use glam::Vec3;
+use vectorcade_shared::{game::AudioOut, normalize_angle, projectile::Projectile3D};
+
+/// Shell speed, slower than the player's so there is time to dodge.
+pub const SHELL_SPEED: f32 = 20.0;
+const SHELL_RANGE: f32 = 40.0;
+/// Enemies hold fire until the player is this close.
+const FIRING_RANGE: f32 = 30.0;
+/// How far off the aim point the turret may be and still fire.
+const ALIGN_TOLERANCE: f32 = 0.05;
+const BARREL_HEIGHT: f32 = 0.8;
 
 /// Types of enemies in the game.
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -9,10 +20,39 @@ pub enum EnemyKind {
     SuperTank,
 }
 
+impl EnemyKind {
+    fn speed(self) -> f32 {
+        match self {
+            EnemyKind::Tank => 2.0,
+            EnemyKind::SuperTank => 3.5,
+        }
+    }
+
+    /// Turret traverse in radians per second.
+    fn turn_rate(self) -> f32 {
+        match self {
+            EnemyKind::Tank => 1.0,
+            EnemyKind::SuperTank => 1.8,
+        }
+    }
+
+    /// Seconds to reload after firing.
+    fn reload(self) -> f32 {
+        match self {
+            EnemyKind::Tank => 3.0,
+            EnemyKind::SuperTank => 2.0,
+        }
+    }
+}
+
 /// An enemy unit.
+#[derive(Clone)]
 pub struct Enemy {
     pub pos: Vec3,
     pub angle: f32,
+    /// Position and turret heading before the latest step, for drawing.
+    pub prev_pos: Vec3,
+    pub prev_angle: f32,
     pub kind: EnemyKind,
     pub alive: bool,
     pub fire_timer: f32,
@@ -20,27 +60,69 @@ pub struct Enemy {
 
 impl Enemy {
     pub fn new(pos: Vec3, kind: EnemyKind) -> Self {
-        Self { pos, angle: 0.0, kind, alive: true, fire_timer: 2.0 }
+        Self { pos, angle: 0.0, prev_pos: pos, prev_angle: 0.0, kind, alive: true, fire_timer: 2.0 }
+    }
+
+    /// The enemy as drawn `alpha` (0..1) of the way through the latest step.
+    pub fn interpolated(&self, alpha: f32) -> Self {
+        Self {
+            pos: self.prev_pos.lerp(self.pos, alpha),
+            angle: self.prev_angle + normalize_angle(self.angle - self.prev_angle) * alpha,
+            ..self.clone()
+        }
+    }
+
+    /// Unit vector the gun points along.
+    pub fn facing(&self) -> Vec3 {
+        Vec3::new(-self.angle.cos(), 0.0, self.angle.sin())
     }
 }
 
-/// Update all enemies - move toward player and fire.
-pub fn update_enemies(enemies: &mut [Enemy], player_pos: Vec3, dt: f32) {
+/// Heading of the flat direction `v`, in the same convention as `Enemy.angle`.
+fn heading(v: Vec3) -> f32 {
+    v.z.atan2(-v.x)
+}
+
+/// Where to aim to hit a target at `target` moving at `target_vel`: its
+/// position after the time a shell takes to cover the distance, refined once.
+pub fn lead_aim(from: Vec3, target: Vec3, target_vel: Vec3) -> Vec3 {
+    let mut aim = target;
+    for _ in 0..2 {
+        let flight = (aim - from).length() / SHELL_SPEED;
+        aim = target + target_vel * flight;
+    }
+    aim
+}
+
+/// Update all enemies - move toward the player, traverse the turret onto a
+/// lead-aimed point and fire shells into `shells` when lined up.
+pub fn update_enemies(
+    enemies: &mut [Enemy],
+    player_pos: Vec3,
+    player_vel: Vec3,
+    shells: &mut Vec<Projectile3D>,
+    audio: &dyn AudioOut,
+    dt: f32,
+) {
     for e in enemies.iter_mut() {
         if !e.alive { continue; }
         let to_player = player_pos - e.pos;
         let dist = to_player.length();
-        if dist > 0.1 {
-            e.angle = to_player.z.atan2(-to_player.x);
-            let speed = match e.kind {
-                EnemyKind::Tank => 2.0,
-                EnemyKind::SuperTank => 3.5,
-            };
-            if dist > 8.0 {
-                let dir = to_player.normalize();
-                e.pos += dir * speed * dt;
-            }
+        if dist > 8.0 {
+            e.pos += to_player.normalize() * e.kind.speed() * dt;
         }
+        // Traverse the turret towards the lead point, no faster than it can turn
+        let error = normalize_angle(heading(lead_aim(e.pos, player_pos, player_vel) - e.pos) - e.angle);
+        let turn = error.clamp(-e.kind.turn_rate() * dt, e.kind.turn_rate() * dt);
+        e.angle = normalize_angle(e.angle + turn);
+        let aligned = (error - turn).abs() < ALIGN_TOLERANCE;
         e.fire_timer -= dt;
+        if e.fire_timer <= 0.0 && aligned && dist < FIRING_RANGE {
+            e.fire_timer = e.kind.reload();
+            let dir = e.facing();
+            let muzzle = e.pos + Vec3::new(0.0, BARREL_HEIGHT, 0.0) + dir * 1.0;
+            shells.push(Projectile3D::new(muzzle, dir, SHELL_SPEED, SHELL_RANGE));
+            emit(audio, SoundEvent::TankFire);
+        }
     }
 }
@@ -8,6 +8,10 @@ mod rendering;
 mod world;
 
 use glam::Vec3;
+use arcade_kit::{
+    sound::{SoundEvent, emit},
+    timestep::{FixedStep, FixedUpdate, run_fixed},
+};
 use vectorcade_shared::{
     Rgba,
     draw::DrawCmd,
@@ -25,6 +29,11 @@ use world::Obstacle;
 pub const GREEN: Rgba = Rgba::GREEN;
 pub const RED: Rgba = Rgba::RED;
 
+/// Seconds the cracked screen shows before the player respawns.
+const CRACK_TIME: f32 = 2.0;
+/// Radius around the player that an enemy shell has to reach.
+const PLAYER_RADIUS: f32 = 1.5;
+
 /// Game state.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum GameState { Instructions, Playing, GameOver }
@@ -33,15 +42,25 @@ pub enum GameState { Instructions, Playing, GameOver }
 pub struct Battlezone {
     pub pos: Vec3,
     pub angle: f32,
+    /// Pose before the latest step; `render` blends from it to the current
+    /// one by the clock's `alpha` so the view turns and drives smoothly.
+    pub prev_pos: Vec3,
+    pub prev_angle: f32,
+    /// Velocity over the last step, which enemies lead their aim by.
+    pub vel: Vec3,
     pub score: u32,
     pub lives: u8,
     pub state: GameState,
     pub enemies: Vec<Enemy>,
     pub obstacles: Vec<Obstacle>,
     pub shots: Vec<Projectile3D>,
+    pub enemy_shells: Vec<Projectile3D>,
     pub fire_cooldown: f32,
+    /// Counts down while the screen is cracked after a hit.
+    pub crack_timer: f32,
     pub font_style: FontStyleId,
     pub blink_timer: f32,
+    pub clock: FixedStep,
 }
 
 impl Default for Battlezone {
@@ -53,24 +72,72 @@ impl Battlezone {
         Self {
             pos: Vec3::ZERO,
             angle: 0.0,
+            prev_pos: Vec3::ZERO,
+            prev_angle: 0.0,
+            vel: Vec3::ZERO,
             score: 0,
             lives: 3,
             state: GameState::Instructions,
             enemies: Vec::new(),
             obstacles: Vec::new(),
             shots: Vec::new(),
+            enemy_shells: Vec::new(),
             fire_cooldown: 0.0,
+            crack_timer: 0.0,
             font_style: FontStyleId::ATARI,
             blink_timer: 0.0,
+            clock: FixedStep::default(),
         }
     }
 
+    /// Put a fresh battlefield in front of the player: full lives, new
+    /// obstacles, nothing in flight.
+    fn start_game(&mut self, ctx: &mut GameCtx) {
+        self.pos = Vec3::ZERO;
+        self.angle = 0.0;
+        // Don't blend across the jump back to the start
+        self.prev_pos = self.pos;
+        self.prev_angle = self.angle;
+        self.vel = Vec3::ZERO;
+        self.score = 0;
+        self.lives = 3;
+        self.enemies.clear();
+        self.shots.clear();
+        self.enemy_shells.clear();
+        self.fire_cooldown = 0.0;
+        self.crack_timer = 0.0;
+        world::spawn_obstacles(&mut self.obstacles, ctx.rng);
+        self.state = GameState::Playing;
+    }
+
+    /// Spawn an enemy tank 15-25 units from the player.
     fn spawn_enemy(&mut self, ctx: &mut GameCtx) {
         let angle = ctx.rng.range_f32(0.0, std::f32::consts::TAU);
         let dist = ctx.rng.range_f32(15.0, 25.0);
-        let pos = Vec3::new(angle.cos() * dist, 0.0, angle.sin() * dist);
+        let pos = self.pos + Vec3::new(angle.cos() * dist, 0.0, angle.sin() * dist);
         self.enemies.push(Enemy::new(pos, EnemyKind::Tank));
     }
+
+    /// An enemy shell got through: lose a life and crack the screen.
+    fn take_hit(&mut self) {
+        self.lives = self.lives.saturating_sub(1);
+        self.crack_timer = CRACK_TIME;
+        self.vel = Vec3::ZERO;
+        self.enemy_shells.clear();
+        if self.lives == 0 {
+            self.state = GameState::GameOver;
+        }
+    }
+
+    /// Back in the fight after a hit, with the battlefield cleared of
+    /// enemies and shells; the next step spawns a fresh enemy at a
+    /// distance from the player.
+    fn respawn(&mut self) {
+        self.enemies.clear();
+        self.shots.clear();
+        self.enemy_shells.clear();
+        self.fire_cooldown = 0.0;
+    }
 }
 
 impl Game for Battlezone {
@@ -79,31 +146,14 @@ impl Game for Battlezone {
     }
 
     fn reset(&mut self, ctx: &mut GameCtx) {
-        self.pos = Vec3::ZERO;
-        self.angle = 0.0;
-        self.score = 0;
-        self.lives = 3;
+        self.start_game(ctx);
         self.state = GameState::Instructions;
-        self.enemies.clear();
-        self.obstacles.clear();
-        self.shots.clear();
-        self.fire_cooldown = 0.0;
         self.blink_timer = 0.0;
-        world::spawn_obstacles(&mut self.obstacles, ctx.rng);
+        self.clock.reset();
     }
 
     fn update(&mut self, ctx: &mut GameCtx, dt: f32) {
-        if self.state == GameState::Instructions {
-            self.blink_timer += dt;
-            if ctx.input.key(Key::Space).went_down { self.state = GameState::Playing; }
-            return;
-        }
-        if self.state == GameState::GameOver { return; }
-        update_player(self, ctx, dt);
-        update_projectiles_3d(&mut self.shots, dt);
-        check_shot_collisions(self);
-        enemies::update_enemies(&mut self.enemies, self.pos, dt);
-        if self.enemies.is_empty() { self.spawn_enemy(ctx); }
+        run_fixed(self, ctx, dt);
     }
 
     fn render(&mut self, _ctx: &mut GameCtx, out: &mut Vec<DrawCmd>) {
@@ -112,17 +162,64 @@ impl Game for Battlezone {
             rendering::render_instructions(out, self.font_style, self.blink_timer);
             return;
         }
+        let alpha = self.clock.alpha();
+        let pos = self.prev_pos.lerp(self.pos, alpha);
+        let angle = self.prev_angle + normalize_angle(self.angle - self.prev_angle) * alpha;
+        let enemies: Vec<Enemy> = self.enemies.iter().map(|e| e.interpolated(alpha)).collect();
         rendering::render_horizon(out);
-        rendering::render_world(out, &self.obstacles, &self.enemies, self.pos, self.angle);
-        rendering::render_shots(out, &self.shots, self.pos, self.angle);
+        rendering::render_world(out, &self.obstacles, &enemies, pos, angle);
+        rendering::render_shots(out, &self.shots, pos, angle);
+        rendering::render_shots(out, &self.enemy_shells, pos, angle);
         rendering::render_crosshair(out);
+        if self.crack_timer > 0.0 || self.state == GameState::GameOver {
+            rendering::render_crack(out);
+        }
         rendering::render_hud(out, self.score, self.lives, self.font_style);
         if self.state == GameState::GameOver {
-            rendering::render_game_over(out, self.font_style);
+            rendering::render_game_over(out, self.font_style, self.blink_timer);
         }
     }
 }
 
+impl FixedUpdate for Battlezone {
+    fn clock(&mut self) -> &mut FixedStep { &mut self.clock }
+
+    fn fixed_update(&mut self, ctx: &mut GameCtx, dt: f32) {
+        self.prev_pos = self.pos;
+        self.prev_angle = self.angle;
+        for e in &mut self.enemies {
+            e.prev_pos = e.pos;
+            e.prev_angle = e.angle;
+        }
+        if self.state == GameState::Instructions {
+            self.blink_timer += dt;
+            if ctx.input.key(Key::Space).went_down { self.state = GameState::Playing; }
+            return;
+        }
+        if self.state == GameState::GameOver {
+            self.blink_timer += dt;
+            if ctx.input.key(Key::Space).went_down { self.start_game(ctx); }
+            return;
+        }
+        // The world holds still behind the cracked screen
+        if self.crack_timer > 0.0 {
+            self.crack_timer -= dt;
+            if self.crack_timer <= 0.0 { self.respawn(); }
+            return;
+        }
+        update_player(self, ctx, dt);
+        update_projectiles_3d(&mut self.shots, dt);
+        update_projectiles_3d(&mut self.enemy_shells, dt);
+        check_shot_collisions(self, ctx);
+        enemies::update_enemies(&mut self.enemies, self.pos, self.vel, &mut self.enemy_shells, ctx.audio, dt);
+        if self.enemy_shells.iter().any(|s| s.hits_sphere(self.pos, PLAYER_RADIUS)) {
+            self.take_hit();
+            return;
+        }
+        if self.enemies.is_empty() { self.spawn_enemy(ctx); }
+    }
+}
+
 fn update_player(game: &mut Battlezone, ctx: &GameCtx, dt: f32) {
     let left = ctx.input.key(Key::Left).is_down;
     let right = ctx.input.key(Key::Right).is_down;
@@ -133,8 +230,10 @@ fn update_player(game: &mut Battlezone, ctx: &GameCtx, dt: f32) {
     game.angle = normalize_angle(game.angle);
     let speed = 5.0;
     let dir = Vec3::new(-game.angle.sin(), 0.0, -game.angle.cos());
+    let start = game.pos;
     if fwd { game.pos += dir * speed * dt; }
     if back { game.pos -= dir * speed * dt * 0.5; }
+    game.vel = (game.pos - start) / dt;
     game.fire_cooldown -= dt;
     if ctx.input.key(Key::Space).went_down && game.fire_cooldown <= 0.0 {
         game.fire_cooldown = 0.5;
@@ -143,16 +242,18 @@ fn update_player(game: &mut Battlezone, ctx: &GameCtx, dt: f32) {
         let start_pos = game.pos + barrel_height + dir * 1.0;
         let shot = Projectile3D::new(start_pos, dir, 30.0, 50.0);
         game.shots.push(shot);
+        emit(ctx.audio, SoundEvent::TankFire);
     }
 }
 
-fn check_shot_collisions(game: &mut Battlezone) {
+fn check_shot_collisions(game: &mut Battlezone, ctx: &GameCtx) {
     for shot in &mut game.shots {
         for enemy in &mut game.enemies {
             if enemy.alive && shot.hits_sphere(enemy.pos, 1.5) {
                 shot.alive = false;
                 enemy.alive = false;
                 game.score += 1000;
+                emit(ctx.audio, SoundEvent::EnemyDestroyed);
                 break;
             }
         }
@@ -82,7 +82,7 @@ pub fn render_hud(out: &mut Vec<DrawCmd>, score: u32, lives: u8, style: FontStyl
 }
 
 /// Render game over text.
-pub fn render_game_over(out: &mut Vec<DrawCmd>, style: FontStyleId) {
+pub fn render_game_over(out: &mut Vec<DrawCmd>, style: FontStyleId, blink_timer: f32) {
     out.push(DrawCmd::Text {
         pos: Vec2::new(-0.28, 0.0),
         text: "GAME OVER".to_string(),
@@ -90,6 +90,39 @@ pub fn render_game_over(out: &mut Vec<DrawCmd>, style: FontStyleId) {
         color: RED,
         style,
     });
+    if (blink_timer % 1.0) < 0.6 {
+        out.push(DrawCmd::Text {
+            pos: Vec2::new(-0.62, -0.3), text: "PRESS SPACE TO PLAY AGAIN".to_string(), size_px: 40.0, color: RED, style,
+        });
+    }
+}
+
+/// Render the cracked screen left by an enemy hit: jagged lines running
+/// out from the impact point.
+pub fn render_crack(out: &mut Vec<DrawCmd>) {
+    let impact = Vec2::new(0.12, -0.08);
+    // Each crack is a run of (heading, length) kinks, so it looks the same every frame
+    let cracks: [&[(f32, f32)]; 7] = [
+        &[(0.2, 0.25), (0.5, 0.3), (0.3, 0.5)],
+        &[(1.1, 0.2), (1.4, 0.35), (1.2, 0.4)],
+        &[(2.0, 0.3), (1.8, 0.25), (2.2, 0.6)],
+        &[(2.9, 0.2), (3.2, 0.4), (2.8, 0.5)],
+        &[(3.7, 0.35), (3.9, 0.3), (3.6, 0.3)],
+        &[(4.6, 0.25), (4.3, 0.3), (4.8, 0.4)],
+        &[(5.5, 0.3), (5.8, 0.2), (5.4, 0.5)],
+    ];
+    for kinks in cracks {
+        let mut p = impact;
+        let mut pts = vec![p];
+        for &(heading, len) in kinks {
+            p += Vec2::from_angle(heading) * len;
+            pts.push(p);
+        }
+        out.push(DrawCmd::Polyline { pts, closed: false, stroke: Stroke::new(GREEN, 2.0) });
+    }
+    // A ring of short cross-cracks around the impact
+    let ring: Vec<Vec2> = (0..=7).map(|i| impact + Vec2::from_angle(i as f32 * 0.8 + 0.3) * 0.12).collect();
+    out.push(DrawCmd::Polyline { pts: ring, closed: false, stroke: Stroke::new(GREEN, 1.5) });
 }
 
 /// Render the 3D world - obstacles and enemies.
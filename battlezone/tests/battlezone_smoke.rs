@@ -1,4 +1,6 @@
 use battlezone::{Battlezone, GameState};
+use arcade_kit::sound::{SoundEvent, SoundLog};
+use glam::Vec3;
 use vectorcade_shared::Xorshift64;
 use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
 use vectorcade_shared::input::{Axis, Button, InputState, Key};
@@ -32,3 +34,38 @@ fn battlezone_advances() {
     g.update(&mut ctx, 0.016);
     assert!(!g.enemies.is_empty(), "should spawn enemy when none exist");
 }
+
+/// Fire button freshly pressed.
+struct Fire;
+impl InputState for Fire {
+    fn key(&self, k: Key) -> Button {
+        if k == Key::Space { Button { is_down: true, went_down: true, went_up: false } } else { Button::UP }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+#[test]
+fn battlezone_emits_sounds() {
+    let audio = SoundLog::new();
+    let mut rng = Xorshift64::new(12345);
+    let mut g = Battlezone::new();
+    let mut ctx = GameCtx {
+        input: &NoInput,
+        audio: &audio,
+        rng: &mut rng,
+        screen: ScreenInfo::default(),
+        now_s: 0.0,
+    };
+    g.reset(&mut ctx);
+    g.state = GameState::Playing;
+    g.update(&mut ctx, 0.016);
+    // Park the spawned enemy dead ahead and shoot it
+    g.enemies[0].pos = Vec3::new(0.0, 0.0, -6.0);
+    ctx.input = &Fire;
+    g.update(&mut ctx, 0.016);
+    assert!(audio.contains(SoundEvent::TankFire));
+    ctx.input = &NoInput;
+    for _ in 0..30 { g.update(&mut ctx, 0.016); }
+    assert!(audio.contains(SoundEvent::EnemyDestroyed));
+}
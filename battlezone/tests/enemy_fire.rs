@@ -0,0 +1,165 @@
+use arcade_kit::sound::{SoundEvent, SoundLog};
+use battlezone::{Battlezone, GameState};
+use glam::Vec3;
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::draw::DrawCmd;
+use vectorcade_shared::game::{Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+/// Forward held down.
+struct Drive;
+impl InputState for Drive {
+    fn key(&self, k: Key) -> Button {
+        if k == Key::Up { Button { is_down: true, went_down: false, went_up: false } } else { Button::UP }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+/// Space freshly pressed.
+struct Fire;
+impl InputState for Fire {
+    fn key(&self, k: Key) -> Button {
+        if k == Key::Space { Button { is_down: true, went_down: true, went_up: false } } else { Button::UP }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+/// A game in play with one enemy tank parked at `enemy`, ready to fire.
+fn duel(ctx: &mut GameCtx, enemy: Vec3) -> Battlezone {
+    let mut g = Battlezone::new();
+    g.reset(ctx);
+    g.state = GameState::Playing;
+    g.update(ctx, 0.016);
+    g.enemies.truncate(1);
+    g.enemies[0].pos = enemy;
+    g.enemies[0].fire_timer = 0.0;
+    g
+}
+
+/// Run until the player is hit or `seconds` pass; true if hit.
+fn until_hit(g: &mut Battlezone, ctx: &mut GameCtx, seconds: f32) -> bool {
+    let lives = g.lives;
+    for _ in 0..(seconds / 0.016) as usize {
+        g.update(ctx, 0.016);
+        if g.lives < lives {
+            return true;
+        }
+    }
+    false
+}
+
+#[test]
+fn enemy_fires_shells_when_lined_up() {
+    let audio = SoundLog::new();
+    let mut rng = Xorshift64::new(12345);
+    let mut ctx = GameCtx { input: &NoInput, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = duel(&mut ctx, Vec3::new(0.0, 0.0, -20.0));
+    for _ in 0..240 {
+        g.update(&mut ctx, 0.016);
+        if !g.enemy_shells.is_empty() {
+            break;
+        }
+    }
+    assert!(!g.enemy_shells.is_empty(), "enemy should fire once its turret is on target");
+    assert!(audio.contains(SoundEvent::TankFire));
+    assert!(until_hit(&mut g, &mut ctx, 3.0), "a shell at a sitting target should hit");
+}
+
+#[test]
+fn enemy_holds_fire_out_of_range() {
+    let audio = SoundLog::new();
+    let mut rng = Xorshift64::new(12345);
+    let mut ctx = GameCtx { input: &NoInput, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = duel(&mut ctx, Vec3::new(0.0, 0.0, -45.0));
+    for _ in 0..60 {
+        g.update(&mut ctx, 0.016);
+    }
+    assert!(g.enemy_shells.is_empty());
+}
+
+#[test]
+fn enemy_leads_a_moving_target() {
+    let audio = SoundLog::new();
+    let mut rng = Xorshift64::new(12345);
+    // Off to the right while the player drives straight ahead
+    let mut ctx = GameCtx { input: &Drive, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = duel(&mut ctx, Vec3::new(20.0, 0.0, 0.0));
+    assert!(until_hit(&mut g, &mut ctx, 6.0), "lead aim should catch a tank on a steady course");
+}
+
+#[test]
+fn hit_cracks_the_screen_and_respawns() {
+    let audio = SoundLog::new();
+    let mut rng = Xorshift64::new(12345);
+    let mut ctx = GameCtx { input: &NoInput, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    // Well away from where the game started
+    let mut g = duel(&mut ctx, Vec3::new(60.0, 0.0, -55.0));
+    g.pos = Vec3::new(60.0, 0.0, -40.0);
+    let clean = {
+        let mut out = Vec::new();
+        g.render(&mut ctx, &mut out);
+        out.len()
+    };
+    assert!(until_hit(&mut g, &mut ctx, 5.0));
+    assert_eq!(g.lives, 2);
+    assert!(g.crack_timer > 0.0);
+    assert!(g.state == GameState::Playing);
+    let mut out = Vec::new();
+    g.render(&mut ctx, &mut out);
+    let lines = out.iter().filter(|c| matches!(c, DrawCmd::Polyline { .. })).count();
+    assert!(out.len() > clean && lines > 0, "cracked screen should draw over the view");
+
+    // Frozen behind the crack, then back with a fresh enemy at a distance
+    for _ in 0..200 {
+        g.update(&mut ctx, 0.016);
+    }
+    assert!(g.crack_timer <= 0.0);
+    assert!(g.enemy_shells.is_empty());
+    assert_eq!(g.enemies.len(), 1);
+    let range = (g.enemies[0].pos - g.pos).length();
+    assert!((10.0..=25.0).contains(&range), "respawned enemy {} away", range);
+}
+
+#[test]
+fn last_life_ends_the_game() {
+    let audio = SoundLog::new();
+    let mut rng = Xorshift64::new(12345);
+    let mut ctx = GameCtx { input: &NoInput, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = duel(&mut ctx, Vec3::new(0.0, 0.0, -15.0));
+    g.lives = 1;
+    assert!(until_hit(&mut g, &mut ctx, 5.0));
+    assert_eq!(g.lives, 0);
+    assert!(g.state == GameState::GameOver);
+}
+
+#[test]
+fn space_restarts_after_game_over() {
+    let audio = SoundLog::new();
+    let mut rng = Xorshift64::new(12345);
+    let mut ctx = GameCtx { input: &NoInput, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = duel(&mut ctx, Vec3::new(0.0, 0.0, -15.0));
+    g.lives = 1;
+    g.score = 2000;
+    assert!(until_hit(&mut g, &mut ctx, 5.0));
+    assert!(g.state == GameState::GameOver);
+    let mut out = Vec::new();
+    g.render(&mut ctx, &mut out);
+    assert!(out.iter().any(|c| matches!(c, DrawCmd::Text { text, .. } if text.contains("PRESS SPACE"))));
+
+    ctx.input = &Fire;
+    g.update(&mut ctx, 0.016);
+    assert!(g.state == GameState::Playing);
+    assert_eq!(g.lives, 3);
+    assert_eq!(g.score, 0);
+    assert!(g.enemy_shells.is_empty());
+    assert!(g.crack_timer <= 0.0);
+}
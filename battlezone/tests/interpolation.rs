@@ -0,0 +1,63 @@
+use battlezone::{Battlezone, GameState};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+/// Drives forward while turning left.
+struct Driving;
+impl InputState for Driving {
+    fn key(&self, k: Key) -> Button {
+        if matches!(k, Key::Up | Key::Left) { Button { is_down: true, went_down: false, went_up: false } } else { Button::UP }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+/// The whole frame, in a form that can be compared with the last one.
+fn frame(g: &mut Battlezone, ctx: &mut GameCtx) -> String {
+    let mut out = Vec::new();
+    g.render(ctx, &mut out);
+    format!("{out:?}")
+}
+
+#[test]
+fn view_moves_smoothly_between_steps() {
+    let mut rng = Xorshift64::new(5);
+    let mut ctx = GameCtx { input: &Driving, audio: &NoAudio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = Battlezone::new();
+    g.reset(&mut ctx);
+    g.state = GameState::Playing;
+    // Get moving, then show it on a 144 Hz display over the 120 Hz
+    // simulation: some frames run no step at all, yet the view should
+    // still move on every one of them
+    g.update(&mut ctx, 1.0 / 60.0);
+    let mut last = frame(&mut g, &mut ctx);
+    for n in 0..30 {
+        g.update(&mut ctx, 1.0 / 144.0);
+        let drawn = frame(&mut g, &mut ctx);
+        assert!(drawn != last, "frame {n} repeated the last one");
+        last = drawn;
+    }
+}
+
+#[test]
+fn view_holds_still_behind_the_cracked_screen() {
+    let mut rng = Xorshift64::new(5);
+    let mut ctx = GameCtx { input: &Driving, audio: &NoAudio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = Battlezone::new();
+    g.reset(&mut ctx);
+    g.state = GameState::Playing;
+    for _ in 0..10 {
+        g.update(&mut ctx, 1.0 / 144.0);
+    }
+    g.crack_timer = 1.0;
+    g.update(&mut ctx, 1.0 / 60.0);
+    let still = frame(&mut g, &mut ctx);
+    for n in 0..10 {
+        g.update(&mut ctx, 1.0 / 144.0);
+        assert!(frame(&mut g, &mut ctx) == still, "frame {n} moved while frozen");
+    }
+}
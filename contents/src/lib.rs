@@ -1,18 +1,27 @@
 //! Contents listing screen for VectorCade.
+//!
+//! Acts as the launcher: Up/Down move the cursor, Enter/Space pick a game.
+//! The host polls [`Contents::take_launch`] to find out what to start.
 
 use glam::Vec2;
 use vectorcade_shared::{
     Rgba,
-    draw::DrawCmd,
+    draw::{DrawCmd, Stroke, rect_wire},
     font::FontStyleId,
     game::{Game, GameCtx, GameMeta},
+    input::Key,
 };
 
 const WHITE: Rgba = Rgba::WHITE;
+const LIST_TOP: f32 = 0.35;
+const LINE_HEIGHT: f32 = 0.15;
 
-/// Static contents screen listing all available games.
+/// Game-selection menu listing the games it is given.
 pub struct Contents {
     font_style: FontStyleId,
+    entries: Vec<&'static str>,
+    cursor: usize,
+    launch: Option<usize>,
 }
 
 impl Default for Contents {
@@ -20,9 +29,24 @@ impl Default for Contents {
 }
 
 impl Contents {
+    /// An empty menu. The game list lives in the `vectorcade-games`
+    /// registry, whose `contents_menu()` builds the full menu from it.
     pub fn new() -> Self {
-        Self { font_style: FontStyleId::ATARI }
+        Self::with_entries(Vec::new())
     }
+
+    /// Build a menu from game names, in display order.
+    pub fn with_entries(entries: Vec<&'static str>) -> Self {
+        Self { font_style: FontStyleId::ATARI, entries, cursor: 0, launch: None }
+    }
+
+    pub fn entries(&self) -> &[&'static str] { &self.entries }
+
+    /// Index of the highlighted entry.
+    pub fn cursor(&self) -> usize { self.cursor }
+
+    /// Take the entry chosen since the last call, if any.
+    pub fn take_launch(&mut self) -> Option<usize> { self.launch.take() }
 }
 
 impl Game for Contents {
@@ -30,9 +54,23 @@ impl Game for Contents {
         GameMeta { name: "Contents", preferred_aspect: Some(4.0 / 3.0) }
     }
 
-    fn reset(&mut self, _ctx: &mut GameCtx) {}
+    fn reset(&mut self, _ctx: &mut GameCtx) {
+        self.launch = None;
+    }
 
-    fn update(&mut self, _ctx: &mut GameCtx, _dt: f32) {}
+    fn update(&mut self, ctx: &mut GameCtx, _dt: f32) {
+        let count = self.entries.len();
+        if count == 0 { return; }
+        if ctx.input.key(Key::Up).went_down {
+            self.cursor = (self.cursor + count - 1) % count;
+        }
+        if ctx.input.key(Key::Down).went_down {
+            self.cursor = (self.cursor + 1) % count;
+        }
+        if ctx.input.key(Key::Enter).went_down || ctx.input.key(Key::Space).went_down {
+            self.launch = Some(self.cursor);
+        }
+    }
 
     fn render(&mut self, _ctx: &mut GameCtx, out: &mut Vec<DrawCmd>) {
         out.push(DrawCmd::Clear { color: Rgba::BLACK });
@@ -46,24 +84,34 @@ impl Game for Contents {
             style: self.font_style,
         });
 
-        // Contents list
-        let lines = [
-            ("- CHESS BOARD IMAGE", 0.35),
-            ("- PONG", 0.20),
-            ("- ASTEROIDS", 0.05),
-            ("- LUNAR LANDER", -0.10),
-            ("- BATTLEZONE", -0.25),
-            ("- TEMPEST", -0.40),
-        ];
-
-        for (text, y) in lines {
+        // Contents list, highlighted entry boxed and at full brightness
+        for (i, name) in self.entries.iter().enumerate() {
+            let y = LIST_TOP - i as f32 * LINE_HEIGHT;
+            let selected = i == self.cursor;
+            let color = if selected { WHITE } else { WHITE.with_a(0.5) };
+            let marker = if selected { ">" } else { "-" };
             out.push(DrawCmd::Text {
                 pos: Vec2::new(-0.55, y),
-                text: text.to_string(),
+                text: format!("{} {}", marker, name.to_uppercase()),
                 size_px: 36.0,
-                color: WHITE,
+                color,
                 style: self.font_style,
             });
+            if selected {
+                out.push(rect_wire(
+                    Vec2::new(-0.60, y - 0.05),
+                    Vec2::new(0.75, y + 0.08),
+                    Stroke::new(WHITE, 1.5),
+                ));
+            }
         }
+
+        out.push(DrawCmd::Text {
+            pos: Vec2::new(-0.85, -0.85),
+            text: "UP/DOWN SELECT - ENTER PLAY - ESC MENU".to_string(),
+            size_px: 28.0,
+            color: WHITE.with_a(0.6),
+            style: self.font_style,
+        });
     }
 }
@@ -0,0 +1,69 @@
+use contents::Contents;
+use vectorcade_games::{contents_menu, games};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::draw::DrawCmd;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+/// Reports a single key as just pressed.
+struct Press(Key);
+impl InputState for Press {
+    fn key(&self, k: Key) -> Button {
+        if k == self.0 { Button { is_down: true, went_down: true, went_up: false } } else { Button::UP }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+fn press(g: &mut Contents, key: Key) {
+    let input = Press(key);
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(12345);
+    let mut ctx = GameCtx {
+        input: &input,
+        audio: &audio,
+        rng: &mut rng,
+        screen: ScreenInfo::default(),
+        now_s: 0.0,
+    };
+    g.update(&mut ctx, 0.016);
+}
+
+#[test]
+fn contents_cursor_wraps_and_launches() {
+    let mut g = Contents::with_entries(vec!["Pong", "Asteroids", "Tempest"]);
+    assert_eq!(g.cursor(), 0);
+    press(&mut g, Key::Up);
+    assert_eq!(g.cursor(), 2, "Up from the first entry wraps to the last");
+    press(&mut g, Key::Down);
+    press(&mut g, Key::Down);
+    assert_eq!(g.cursor(), 1);
+    assert_eq!(g.take_launch(), None);
+    press(&mut g, Key::Enter);
+    assert_eq!(g.take_launch(), Some(1));
+    assert_eq!(g.take_launch(), None, "launch request is consumed");
+}
+
+#[test]
+fn registry_menu_lists_every_game() {
+    let mut g = contents_menu();
+    let names: Vec<&str> = games().iter().map(|e| e.name).collect();
+    assert_eq!(g.entries(), names.as_slice());
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(12345);
+    let mut ctx = GameCtx { input: &Press(Key::Space), audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut out = Vec::new();
+    g.render(&mut ctx, &mut out);
+    for name in names {
+        let line = name.to_uppercase();
+        assert!(out.iter().any(|c| matches!(c, DrawCmd::Text { text, .. } if text.ends_with(&line))), "{line} missing");
+    }
+}
+
+#[test]
+fn plain_menu_starts_empty() {
+    assert!(Contents::default().entries().is_empty());
+}
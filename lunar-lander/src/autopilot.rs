@@ -0,0 +1,124 @@
+//! Autopilot for Lunar Lander: flies onto the nearest pad, or only keeps
+//! the descent rate survivable while the pilot steers.
+
+use crate::lander::Lander;
+use crate::physics::{Controls, GRAVITY, THRUST, radar_altitude};
+use crate::terrain::{LandingPad, Terrain};
+
+/// Height above the highest ground on the way that the lander holds
+/// while flying to the pad and lining up over it.
+const CLEARANCE: f32 = 0.2;
+/// Ground this far either side of the lander counts as being on the way.
+const BODY_MARGIN: f32 = 0.06;
+/// Furthest ahead the route is checked for high ground.
+const LOOKAHEAD: f32 = 0.6;
+const CRUISE_SPEED: f32 = 0.45;
+/// Sideways deceleration planned for when braking over the pad, well
+/// inside what [`MAX_TILT`] gives so the turn has time to happen.
+const BRAKING: f32 = 0.05;
+const MAX_TILT: f32 = 0.6;
+/// Tilt allowed within [`LOW_ALTITUDE`] of the ground.
+const LOW_TILT: f32 = 0.12;
+const LOW_ALTITUDE: f32 = 0.15;
+/// Descent rate at touchdown; the allowed rate grows with height.
+const TOUCHDOWN_SPEED: f32 = 0.06;
+const DESCENT_PER_HEIGHT: f32 = 0.5;
+const MAX_DESCENT: f32 = 0.35;
+/// Sideways drift tolerated when starting the final descent.
+const DRIFT: f32 = 0.03;
+
+/// How much of the flying the autopilot does.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AutopilotMode {
+    /// Fly to a pad and land, ignoring the pilot.
+    Full,
+    /// Leave the controls to the pilot but add throttle whenever the
+    /// lander is sinking too fast for its altitude.
+    Assist,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Autopilot {
+    pub mode: AutopilotMode,
+}
+
+impl Autopilot {
+    pub fn new(mode: AutopilotMode) -> Self {
+        Self { mode }
+    }
+
+    /// The pad a full-auto landing heads for: the nearest, the richest on a tie.
+    pub fn target<'a>(&self, lander: &Lander, terrain: &'a Terrain) -> Option<&'a LandingPad> {
+        let distance = |p: &LandingPad| terrain.wrap_dx(lander.pos.x, (p.x_min + p.x_max) / 2.0).abs();
+        terrain
+            .pads
+            .iter()
+            .min_by(|a, b| distance(a).total_cmp(&distance(b)).then(b.multiplier.cmp(&a.multiplier)))
+    }
+
+    /// Turn the pilot's `controls` into what the lander should fly.
+    pub fn steer(&self, lander: &Lander, terrain: &Terrain, controls: Controls) -> Controls {
+        match self.mode {
+            AutopilotMode::Full => self.target(lander, terrain).map_or(controls, |pad| fly_to(lander, terrain, pad)),
+            AutopilotMode::Assist => limit_descent(lander, terrain, controls),
+        }
+    }
+}
+
+/// Fastest safe sinking speed at `height` above the ground.
+fn descent_limit(height: f32) -> f32 {
+    (TOUCHDOWN_SPEED + DESCENT_PER_HEIGHT * height.max(0.0)).min(MAX_DESCENT)
+}
+
+/// Throttle that gives an upward acceleration of `lift` at the current tilt.
+fn throttle_for(lander: &Lander, lift: f32) -> f32 {
+    (lift / (THRUST * lander.angle.cos().max(0.3))).clamp(0.0, 1.0)
+}
+
+/// PD guidance: cruise over the terrain to the pad, hover until centred
+/// and still, then descend on a rate that slows towards touchdown.
+fn fly_to(lander: &Lander, terrain: &Terrain, pad: &LandingPad) -> Controls {
+    let leg_depth = -Lander::LEGS[0][1].y;
+    let foot_span = Lander::LEGS[1][1].x;
+    let (pos, vel) = (lander.pos, lander.vel);
+    let dx = terrain.wrap_dx(pos.x, (pad.x_min + pad.x_max) / 2.0);
+    let height = pos.y - leg_depth - pad.y;
+    let tolerance = ((pad.x_max - pad.x_min) / 2.0 - foot_span - 0.005).max(0.002);
+    let over_pad = dx.abs() < tolerance && vel.x.abs() < DRIFT;
+
+    let vx_goal = dx.signum() * (2.0 * BRAKING * dx.abs()).sqrt().min(dx.abs() * 1.5).min(CRUISE_SPEED);
+    let ax = (vx_goal - vel.x) * 1.5;
+    let vy_goal = if over_pad {
+        -descent_limit(height)
+    } else {
+        let hold = route_top(terrain, pos.x, dx).max(pad.y) + CLEARANCE + leg_depth;
+        ((hold - pos.y) * 1.2).clamp(-0.3, 0.25)
+    };
+    let ay = (vy_goal - vel.y) * 2.0 + GRAVITY;
+
+    let max_tilt = if radar_altitude(lander, terrain) < LOW_ALTITUDE { LOW_TILT } else { MAX_TILT };
+    let tilt = ax.atan2(ay.max(0.05)).clamp(-max_tilt, max_tilt);
+    Controls { turn: ((tilt - lander.angle) * 4.0).clamp(-1.0, 1.0), throttle: throttle_for(lander, ay) }
+}
+
+/// Highest ground between `x` and the point `dx` along, widened by the body.
+fn route_top(terrain: &Terrain, x: f32, dx: f32) -> f32 {
+    let reach = dx.clamp(-LOOKAHEAD, LOOKAHEAD);
+    let (lo, hi) = (reach.min(0.0) - BODY_MARGIN, reach.max(0.0) + BODY_MARGIN);
+    terrain
+        .points
+        .iter()
+        .filter(|p| (lo..=hi).contains(&terrain.wrap_dx(x, p.x)))
+        .map(|p| p.y)
+        .fold(terrain.surface_y_at(x + lo).max(terrain.surface_y_at(x + hi)), f32::max)
+}
+
+/// Raise the pilot's throttle just enough to keep the sink rate safe.
+fn limit_descent(lander: &Lander, terrain: &Terrain, controls: Controls) -> Controls {
+    let floor = -descent_limit(radar_altitude(lander, terrain));
+    if lander.vel.y >= floor {
+        return controls;
+    }
+    let needed = throttle_for(lander, (floor - lander.vel.y) * 2.0 + GRAVITY);
+    Controls { throttle: controls.throttle.max(needed), ..controls }
+}
@@ -0,0 +1,64 @@
+//! Scrolling camera for Lunar Lander.
+
+use glam::Vec2;
+
+use crate::lander::Lander;
+use crate::terrain::Terrain;
+
+/// Follows the lander across the terrain and snaps to a close-up near the
+/// ground, like the 1979 cabinet.
+pub struct Camera {
+    /// World point drawn at the centre of the screen.
+    pub center: Vec2,
+    /// Screen units per world unit.
+    pub zoom: f32,
+}
+
+impl Camera {
+    /// Height above the surface at which the close-up kicks in.
+    pub const ZOOM_ALTITUDE: f32 = 0.3;
+    /// Zoom factor of the close-up view.
+    pub const CLOSE_ZOOM: f32 = 2.5;
+    /// Climbing this far past the threshold zooms back out, so the view
+    /// doesn't flicker while hovering at the boundary.
+    const ZOOM_OUT_ALTITUDE: f32 = Self::ZOOM_ALTITUDE * 1.25;
+
+    pub fn new() -> Self {
+        Self { center: Vec2::ZERO, zoom: 1.0 }
+    }
+
+    pub fn is_close(&self) -> bool {
+        self.zoom > 1.0
+    }
+
+    /// Track the lander, switching between the wide and close-up views.
+    pub fn follow(&mut self, lander: &Lander, terrain: &Terrain) {
+        let ground = terrain.surface_y_at(lander.pos.x);
+        let altitude = lander.pos.y - ground;
+        if altitude < Self::ZOOM_ALTITUDE {
+            self.zoom = Self::CLOSE_ZOOM;
+        } else if altitude > Self::ZOOM_OUT_ALTITUDE {
+            self.zoom = 1.0;
+        }
+        self.center = self.framing(lander, terrain).center;
+    }
+
+    /// The view centred on `lander` at the current zoom, without changing
+    /// zoom; used to frame the lander as drawn between steps.
+    pub fn framing(&self, lander: &Lander, terrain: &Terrain) -> Self {
+        let ground = terrain.surface_y_at(lander.pos.x);
+        // Close up, frame the lander and the ground beneath it
+        let y = if self.is_close() { (lander.pos.y + ground) / 2.0 } else { 0.0 };
+        Self { center: Vec2::new(lander.pos.x, y), zoom: self.zoom }
+    }
+
+    /// Map a world point to the screen, taking the short way round the
+    /// wrapped terrain.
+    pub fn to_screen(&self, p: Vec2, terrain: &Terrain) -> Vec2 {
+        Vec2::new(terrain.wrap_dx(self.center.x, p.x), p.y - self.center.y) * self.zoom
+    }
+}
+
+impl Default for Camera {
+    fn default() -> Self { Self::new() }
+}
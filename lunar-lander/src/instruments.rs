@@ -0,0 +1,71 @@
+//! Flight instruments for Lunar Lander: speeds, radar altitude, a velocity
+//! vector and the approach warning.
+
+use glam::Vec2;
+use vectorcade_shared::{
+    draw::{DrawCmd, Stroke},
+    font::FontStyleId,
+};
+
+use crate::lander::Lander;
+use crate::physics::{SAFE_VELOCITY, radar_altitude};
+use crate::rendering::WHITE;
+use crate::terrain::Terrain;
+
+/// Centre and radius of the velocity-vector dial.
+const DIAL: Vec2 = Vec2::new(0.75, 0.3);
+const DIAL_RADIUS: f32 = 0.12;
+/// The dial's rim is twice the safe touchdown speed.
+const DIAL_SCALE: f32 = DIAL_RADIUS / (2.0 * SAFE_VELOCITY);
+/// Speeds below this show no direction arrow.
+const STILL: f32 = 0.005;
+
+pub fn render_instruments(out: &mut Vec<DrawCmd>, lander: &Lander, terrain: &Terrain, style: FontStyleId) {
+    let readouts = [
+        (format!("ALT: {:.2}", radar_altitude(lander, terrain).max(0.0)), 0.9, None),
+        (format!("HSPEED: {:.2}", lander.vel.x.abs()), 0.75, Some(Vec2::new(lander.vel.x, 0.0))),
+        (format!("VSPEED: {:.2}", lander.vel.y.abs()), 0.6, Some(Vec2::new(0.0, lander.vel.y))),
+    ];
+    for (text, y, arrow) in readouts {
+        out.push(DrawCmd::Text { pos: Vec2::new(0.35, y), text, size_px: 48.0, color: WHITE, style });
+        if let Some(v) = arrow.filter(|v| v.length() > STILL) {
+            render_arrow(out, Vec2::new(0.92, y + 0.02), v.normalize() * 0.04);
+        }
+    }
+    render_velocity_dial(out, lander.vel);
+}
+
+/// Arrow of length `v` centred on `at`.
+fn render_arrow(out: &mut Vec<DrawCmd>, at: Vec2, v: Vec2) {
+    let (tail, tip) = (at - v / 2.0, at + v / 2.0);
+    let back = -v * 0.5;
+    let wing = Vec2::new(-back.y, back.x) * 0.6;
+    let stroke = Stroke::new(WHITE, 1.5);
+    out.push(DrawCmd::Polyline { pts: vec![tail, tip], closed: false, stroke });
+    out.push(DrawCmd::Polyline { pts: vec![tip + back + wing, tip, tip + back - wing], closed: false, stroke });
+}
+
+/// Dial with a ring at the safe touchdown speed and a needle along the
+/// velocity, pinned to the rim when off the scale.
+fn render_velocity_dial(out: &mut Vec<DrawCmd>, vel: Vec2) {
+    let circle = |r: f32| {
+        (0..24).map(|i| DIAL + Vec2::from_angle(i as f32 * std::f32::consts::TAU / 24.0) * r).collect::<Vec<_>>()
+    };
+    out.push(DrawCmd::Polyline { pts: circle(DIAL_RADIUS), closed: true, stroke: Stroke::new(WHITE, 1.0) });
+    out.push(DrawCmd::Polyline { pts: circle(SAFE_VELOCITY * DIAL_SCALE), closed: true, stroke: Stroke::new(WHITE, 0.5) });
+    let needle = (vel * DIAL_SCALE).clamp_length_max(DIAL_RADIUS);
+    out.push(DrawCmd::Polyline { pts: vec![DIAL, DIAL + needle], closed: false, stroke: Stroke::new(WHITE, 2.0) });
+}
+
+/// Flashing approach warning.
+pub fn render_warning(out: &mut Vec<DrawCmd>, warning: &str, blink_timer: f32, style: FontStyleId) {
+    if (blink_timer % 0.5) < 0.3 {
+        out.push(DrawCmd::Text {
+            pos: Vec2::new(-0.25, 0.3),
+            text: warning.to_string(),
+            size_px: 64.0,
+            color: WHITE,
+            style,
+        });
+    }
+}
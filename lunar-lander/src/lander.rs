@@ -2,30 +2,91 @@
 
 use glam::Vec2;
 
+use crate::terrain::Terrain;
+
 /// The lunar lander spacecraft.
+#[derive(Clone)]
 pub struct Lander {
     pub pos: Vec2,
     pub vel: Vec2,
     pub angle: f32,
+    /// Position and attitude before the latest step, blended towards the
+    /// current ones when drawing.
+    pub prev_pos: Vec2,
+    pub prev_angle: f32,
     pub fuel: f32,
+    /// Throttle the pilot has set, 0 to 1.
+    pub lever: f32,
+    /// Throttle actually delivered this step, 0 to 1.
+    pub throttle: f32,
     pub thrusting: bool,
 }
 
 impl Lander {
+    /// Body triangle, in lander space.
+    pub const BODY: [Vec2; 3] = [Vec2::new(0.0, 0.04), Vec2::new(-0.025, -0.02), Vec2::new(0.025, -0.02)];
+    /// Left and right legs, each from hip to foot, in lander space.
+    pub const LEGS: [[Vec2; 2]; 2] = [
+        [Vec2::new(-0.02, -0.02), Vec2::new(-0.035, -0.05)],
+        [Vec2::new(0.02, -0.02), Vec2::new(0.035, -0.05)],
+    ];
+    /// Thrust flame, in lander space.
+    pub const FLAME: [Vec2; 3] = [Vec2::new(-0.012, -0.02), Vec2::new(0.0, -0.07), Vec2::new(0.012, -0.02)];
+
     pub fn new() -> Self {
+        let pos = Vec2::new(0.0, 0.7);
         Self {
-            pos: Vec2::new(0.0, 0.7),
+            pos,
             vel: Vec2::new(0.1, 0.0),
             angle: 0.0,
+            prev_pos: pos,
+            prev_angle: 0.0,
             fuel: 100.0,
+            lever: 0.0,
+            throttle: 0.0,
             thrusting: false,
         }
     }
 
+    /// The lander as drawn `alpha` (0..1) of the way through the latest
+    /// step, taking the short way across the terrain seam.
+    pub fn interpolated(&self, alpha: f32, terrain: &Terrain) -> Self {
+        let x = self.prev_pos.x + terrain.wrap_dx(self.prev_pos.x, self.pos.x) * alpha;
+        let y = self.prev_pos.y + (self.pos.y - self.prev_pos.y) * alpha;
+        Self {
+            pos: Vec2::new(terrain.wrap_x(x), y),
+            angle: self.prev_angle + (self.angle - self.prev_angle) * alpha,
+            ..self.clone()
+        }
+    }
+
     pub fn direction(&self) -> Vec2 {
         Vec2::new(self.angle.sin(), self.angle.cos())
     }
 
+    /// Rotate a lander-space point by the current attitude and place it in the world.
+    pub fn to_world(&self, v: Vec2) -> Vec2 {
+        let (s, c) = (self.angle.sin(), self.angle.cos());
+        self.pos + Vec2::new(v.x * c - v.y * s, v.x * s + v.y * c)
+    }
+
+    /// End of the thrust flame, in lander space; it reaches further the
+    /// harder the engine burns.
+    pub fn flame_tip(&self) -> Vec2 {
+        let [root, tip, _] = Self::FLAME;
+        Vec2::new(tip.x, root.y + (tip.y - root.y) * self.throttle)
+    }
+
+    /// Body triangle in world space.
+    pub fn hull(&self) -> [Vec2; 3] {
+        Self::BODY.map(|v| self.to_world(v))
+    }
+
+    /// Left and right foot in world space.
+    pub fn feet(&self) -> [Vec2; 2] {
+        Self::LEGS.map(|[_, foot]| self.to_world(foot))
+    }
+
     pub fn is_out_of_fuel(&self) -> bool {
         self.fuel <= 0.0
     }
@@ -1,20 +1,44 @@
 //! Lunar Lander game implementation.
 
+mod autopilot;
+mod camera;
+mod instruments;
 mod lander;
+mod particles;
 mod physics;
 mod rendering;
 mod terrain;
 
+use arcade_kit::{
+    sound::{SoundEvent, emit},
+    timestep::{FixedStep, FixedUpdate, run_fixed},
+};
 use vectorcade_shared::{
     Rgba,
     draw::DrawCmd,
     font::FontStyleId,
-    game::{Game, GameCtx, GameMeta},
+    game::{AudioOut, Game, GameCtx, GameMeta},
     input::Key,
 };
 
-use lander::Lander;
-use terrain::Terrain;
+pub use autopilot::{Autopilot, AutopilotMode};
+pub use camera::Camera;
+pub use physics::Controls;
+pub use lander::Lander;
+pub use arcade_kit::debris::Debris;
+pub use particles::{Dust, tumble};
+pub use terrain::{LandingPad, Terrain, TerrainError};
+
+/// Terrain difficulty added each round of a campaign.
+const DIFFICULTY_PER_ROUND: f32 = 0.15;
+/// Seconds each attract page shows: instructions, then the demo flight.
+const ATTRACT_PAGE: f32 = 6.0;
+/// Flies the attract-mode demo.
+const DEMO_PILOT: Autopilot = Autopilot { mode: AutopilotMode::Full };
+
+/// Keeps the attract-mode demo quiet.
+struct Mute;
+impl AudioOut for Mute {}
 
 /// Game state enumeration.
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -23,16 +47,33 @@ pub enum GameState {
     Playing,
     Landed,
     Crashed,
+    /// Out of fuel; the campaign summary is showing.
+    GameOver,
 }
 
 /// Main game struct.
 pub struct LunarLander {
     pub lander: Lander,
     pub terrain: Terrain,
+    pub camera: Camera,
+    /// Pieces of a crashed lander.
+    pub debris: Vec<Debris>,
+    pub dust: Vec<Dust>,
+    /// Flies or assists the player's lander; `A` toggles descent assist.
+    pub autopilot: Option<Autopilot>,
     pub state: GameState,
     pub score: u32,
+    /// Round of the campaign being flown, from 1.
+    pub round: u32,
+    pub landings: u32,
+    pub crashes: u32,
+    /// Points earned by the most recent landing.
+    pub last_points: u32,
+    /// Seconds left on the landed/crashed screen before the next round.
+    pub round_timer: f32,
     pub font_style: FontStyleId,
     pub blink_timer: f32,
+    pub clock: FixedStep,
 }
 
 impl Default for LunarLander {
@@ -40,14 +81,151 @@ impl Default for LunarLander {
 }
 
 impl LunarLander {
+    /// Seconds the landed/crashed screen shows before the next round.
+    pub const ROUND_PAUSE: f32 = 3.0;
+    /// Fuel lost in a crash.
+    pub const CRASH_FUEL: f32 = 25.0;
+
     pub fn new() -> Self {
         Self {
             lander: Lander::new(),
             terrain: Terrain::new(),
+            camera: Camera::new(),
+            debris: Vec::new(),
+            dust: Vec::new(),
+            autopilot: None,
             state: GameState::Instructions,
             score: 0,
+            round: 1,
+            landings: 0,
+            crashes: 0,
+            last_points: 0,
+            round_timer: 0.0,
             font_style: FontStyleId::ATARI,
             blink_timer: 0.0,
+            clock: FixedStep::default(),
+        }
+    }
+
+    /// Start a fresh campaign with a full tank.
+    pub fn start_campaign(&mut self, ctx: &mut GameCtx) {
+        self.lander = Lander::new();
+        self.round = 1;
+        self.new_terrain(ctx);
+        self.camera = Camera::new();
+        self.debris.clear();
+        self.dust.clear();
+        self.score = 0;
+        self.landings = 0;
+        self.crashes = 0;
+        self.last_points = 0;
+        self.round_timer = 0.0;
+    }
+
+    /// Fly over new terrain with whatever fuel is left, or end the campaign.
+    pub fn next_round(&mut self, ctx: &mut GameCtx) {
+        if self.lander.is_out_of_fuel() {
+            self.state = GameState::GameOver;
+            return;
+        }
+        self.lander = Lander { fuel: self.lander.fuel, ..Lander::new() };
+        self.round += 1;
+        self.new_terrain(ctx);
+        self.camera = Camera::new();
+        self.debris.clear();
+        self.dust.clear();
+        self.state = GameState::Playing;
+    }
+
+    /// One step of flight, flown by `pilot` if set; returns how it ended.
+    fn fly(&mut self, ctx: &mut GameCtx, pilot: Option<Autopilot>, dt: f32) -> GameState {
+        let controls = physics::read_controls(&mut self.lander, ctx.input, dt);
+        let controls = pilot.map_or(controls, |p| p.steer(&self.lander, &self.terrain, controls));
+        physics::update_lander(&mut self.lander, controls, ctx.audio, dt);
+        self.lander.pos.x = self.terrain.wrap_x(self.lander.pos.x);
+        self.camera.follow(&self.lander, &self.terrain);
+        particles::kick_up_dust(&mut self.dust, &self.lander, &self.terrain, ctx.rng, dt);
+        self.update_particles(dt);
+        physics::check_landing(&mut self.lander, &self.terrain, dt)
+    }
+
+    /// Attract mode: the autopilot lands on fresh terrain over and over.
+    fn fly_demo(&mut self, ctx: &mut GameCtx, dt: f32) {
+        if self.round_timer > 0.0 {
+            self.update_particles(dt);
+            self.round_timer -= dt;
+            if self.round_timer <= 0.0 {
+                self.start_campaign(ctx);
+            }
+            return;
+        }
+        let mut quiet = GameCtx { input: ctx.input, audio: &Mute, rng: &mut *ctx.rng, screen: ctx.screen, now_s: ctx.now_s };
+        let outcome = self.fly(&mut quiet, Some(DEMO_PILOT), dt);
+        if outcome != GameState::Playing {
+            self.round_timer = Self::ROUND_PAUSE;
+            self.lander.throttle = 0.0;
+            self.lander.thrusting = false;
+            if outcome == GameState::Crashed {
+                self.debris = particles::shatter(&self.lander, ctx.rng);
+            }
+        }
+    }
+
+    fn update_particles(&mut self, dt: f32) {
+        for piece in &mut self.debris {
+            particles::tumble(piece, &self.terrain, dt);
+        }
+        for puff in &mut self.dust {
+            puff.update(&self.terrain, dt);
+        }
+        self.dust.retain(Dust::is_alive);
+    }
+
+    /// Terrain for the current round, from a seed shown on the HUD so a
+    /// layout can be flown again with [`Terrain::from_seed`].
+    fn new_terrain(&mut self, ctx: &mut GameCtx) {
+        let difficulty = ((self.round - 1) as f32 * DIFFICULTY_PER_ROUND).min(1.0);
+        self.terrain = Terrain::from_seed(ctx.rng.next_u32() as u64, difficulty);
+    }
+
+    fn touch_down(&mut self, ctx: &mut GameCtx) {
+        self.lander.lever = 0.0;
+        self.lander.throttle = 0.0;
+        if self.lander.thrusting {
+            self.lander.thrusting = false;
+            emit(ctx.audio, SoundEvent::ThrustStop);
+        }
+        self.round_timer = Self::ROUND_PAUSE;
+        if self.state == GameState::Landed {
+            let multiplier = self.terrain.pad_at(self.lander.feet()[0].x).map_or(1, |p| p.multiplier);
+            self.last_points = physics::calculate_score(&self.lander, multiplier);
+            self.score += self.last_points;
+            self.landings += 1;
+            emit(ctx.audio, SoundEvent::LanderLand);
+        } else {
+            self.lander.fuel = (self.lander.fuel - Self::CRASH_FUEL).max(0.0);
+            self.debris = particles::shatter(&self.lander, ctx.rng);
+            self.crashes += 1;
+            emit(ctx.audio, SoundEvent::LanderCrash);
+        }
+    }
+
+    /// Terrain, lander and particles, seen through the camera. The lander
+    /// is drawn part way between its last two steps and the camera framed
+    /// on it there, so both glide at any display rate.
+    fn render_flight(&self, out: &mut Vec<DrawCmd>) {
+        let lander = self.lander.interpolated(self.clock.alpha(), &self.terrain);
+        let camera = self.camera.framing(&lander, &self.terrain);
+        rendering::render_terrain(out, &self.terrain, &camera);
+        // A crashed lander is drawn as its debris
+        if self.debris.is_empty() {
+            rendering::render_lander(out, &lander, &self.terrain, &camera);
+        }
+        for piece in &self.debris {
+            rendering::render_debris(out, piece, &self.terrain, &camera);
+        }
+        for puff in &self.dust {
+            rendering::render_dust(out, puff, &self.terrain, &camera);
         }
     }
 }
@@ -58,42 +236,95 @@ impl Game for LunarLander {
     }
 
     fn reset(&mut self, ctx: &mut GameCtx) {
-        self.lander = Lander::new();
-        self.terrain = Terrain::generate(ctx.rng);
+        self.start_campaign(ctx);
         self.state = GameState::Instructions;
-        self.score = 0;
         self.blink_timer = 0.0;
+        self.clock.reset();
     }
 
     fn update(&mut self, ctx: &mut GameCtx, dt: f32) {
-        if self.state == GameState::Instructions {
-            self.blink_timer += dt;
-            if ctx.input.key(Key::Space).went_down {
-                self.state = GameState::Playing;
-            }
-            return;
-        }
-        if self.state != GameState::Playing { return; }
-        physics::update_lander(&mut self.lander, ctx, dt);
-        self.state = physics::check_landing(&self.lander, &self.terrain);
-        if self.state == GameState::Landed {
-            self.score = physics::calculate_score(&self.lander);
-        }
+        run_fixed(self, ctx, dt);
     }
 
     fn render(&mut self, _ctx: &mut GameCtx, out: &mut Vec<DrawCmd>) {
         out.push(DrawCmd::Clear { color: Rgba::BLACK });
         if self.state == GameState::Instructions {
-            rendering::render_instructions(out, self.font_style, self.blink_timer);
+            if ((self.blink_timer / ATTRACT_PAGE) as usize).is_multiple_of(2) {
+                rendering::render_instructions(out, self.font_style, self.blink_timer);
+            } else {
+                self.render_flight(out);
+                rendering::render_demo(out, self.font_style, self.blink_timer);
+            }
+            return;
+        }
+        if self.state == GameState::GameOver {
+            let tally = (self.round, self.landings, self.crashes);
+            rendering::render_summary(out, self.score, tally, self.blink_timer, self.font_style);
             return;
         }
-        rendering::render_terrain(out, &self.terrain);
-        rendering::render_lander(out, &self.lander);
-        rendering::render_hud(out, &self.lander, self.font_style);
+        self.render_flight(out);
+        rendering::render_hud(out, &self.lander, self.score, self.round, self.font_style);
+        rendering::render_seed(out, self.terrain.seed, self.font_style);
+        instruments::render_instruments(out, &self.lander, &self.terrain, self.font_style);
+        if let Some(pilot) = self.autopilot {
+            rendering::render_autopilot(out, pilot.mode, self.font_style);
+        }
+        if self.state == GameState::Playing
+            && let Some(warning) = physics::approach_warning(&self.lander, &self.terrain)
+        {
+            instruments::render_warning(out, warning, self.blink_timer, self.font_style);
+        }
         if self.state == GameState::Landed {
-            rendering::render_landed(out, self.score, self.font_style);
+            rendering::render_landed(out, self.last_points, self.font_style);
         } else if self.state == GameState::Crashed {
-            rendering::render_crashed(out, self.font_style);
+            rendering::render_crashed(out, Self::CRASH_FUEL, self.font_style);
+        }
+    }
+}
+
+impl FixedUpdate for LunarLander {
+    fn clock(&mut self) -> &mut FixedStep { &mut self.clock }
+
+    fn fixed_update(&mut self, ctx: &mut GameCtx, dt: f32) {
+        self.lander.prev_pos = self.lander.pos;
+        self.lander.prev_angle = self.lander.angle;
+        match self.state {
+            GameState::Instructions => {
+                self.blink_timer += dt;
+                if ctx.input.key(Key::Space).went_down {
+                    self.start_campaign(ctx);
+                    self.state = GameState::Playing;
+                    return;
+                }
+                self.fly_demo(ctx, dt);
+            }
+            GameState::Landed | GameState::Crashed => {
+                self.update_particles(dt);
+                self.round_timer -= dt;
+                if self.round_timer <= 0.0 || ctx.input.key(Key::Space).went_down {
+                    self.next_round(ctx);
+                }
+            }
+            GameState::GameOver => {
+                self.blink_timer += dt;
+                if ctx.input.key(Key::Space).went_down {
+                    self.start_campaign(ctx);
+                    self.state = GameState::Playing;
+                }
+            }
+            GameState::Playing => {
+                self.blink_timer += dt;
+                if ctx.input.key(Key::A).went_down {
+                    self.autopilot = match self.autopilot {
+                        None => Some(Autopilot::new(AutopilotMode::Assist)),
+                        Some(_) => None,
+                    };
+                }
+                self.state = self.fly(ctx, self.autopilot, dt);
+                if self.state != GameState::Playing {
+                    self.touch_down(ctx);
+                }
+            }
         }
     }
 }
@@ -0,0 +1,124 @@
+//! Crash debris and engine dust for Lunar Lander.
+
+use arcade_kit::debris::Debris;
+use glam::Vec2;
+use vectorcade_shared::GameRng;
+
+use crate::lander::Lander;
+use crate::physics::GRAVITY;
+use crate::terrain::Terrain;
+
+/// Share of the normal speed kept by a bouncing fragment.
+const RESTITUTION: f32 = 0.45;
+/// Share of the sliding speed kept by a bouncing fragment.
+const FRICTION: f32 = 0.7;
+/// Bounces slower than this leave the fragment lying still.
+const REST_SPEED: f32 = 0.03;
+/// Flame tips closer than this to the ground raise dust.
+const DUST_RANGE: f32 = 0.15;
+/// Dust puffs per second at full throttle right on the ground.
+const DUST_RATE: f32 = 90.0;
+const MAX_DUST: usize = 200;
+
+/// Move a piece of the wrecked lander for `dt`: fall under lunar gravity
+/// and bounce off the terrain.
+pub fn tumble(piece: &mut Debris, terrain: &Terrain, dt: f32) {
+    piece.vel.y -= GRAVITY * dt;
+    piece.drift(dt);
+    piece.pos.x = terrain.wrap_x(piece.pos.x);
+    // Push the deeper end back out of the ground and reflect off the slope
+    let (a, b) = piece.endpoints();
+    let depth = |p: Vec2| terrain.surface_y_at(p.x) - p.y;
+    let low = if depth(a) > depth(b) { a } else { b };
+    if depth(low) <= 0.0 {
+        return;
+    }
+    piece.pos.y += depth(low);
+    let normal = surface_normal(terrain, low.x);
+    let into = piece.vel.dot(normal);
+    if into < 0.0 {
+        let slide = piece.vel - normal * into;
+        piece.vel = slide * FRICTION - normal * into * RESTITUTION;
+        piece.spin *= FRICTION;
+        if piece.vel.length() < REST_SPEED {
+            piece.vel = Vec2::ZERO;
+            piece.spin = 0.0;
+        }
+    }
+}
+
+/// A puff of dust blown off the surface by the exhaust.
+pub struct Dust {
+    pub pos: Vec2,
+    pub vel: Vec2,
+    pub lifetime: f32,
+}
+
+impl Dust {
+    pub const MAX_LIFETIME: f32 = 0.8;
+
+    pub fn update(&mut self, terrain: &Terrain, dt: f32) {
+        self.vel.y -= GRAVITY * dt;
+        self.pos += self.vel * dt;
+        self.pos.x = terrain.wrap_x(self.pos.x);
+        self.lifetime -= dt;
+        if self.pos.y < terrain.surface_y_at(self.pos.x) {
+            self.lifetime = 0.0;
+        }
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.lifetime > 0.0
+    }
+
+    pub fn alpha(&self) -> f32 {
+        (self.lifetime / Self::MAX_LIFETIME).clamp(0.0, 1.0)
+    }
+}
+
+/// Break the lander's body and legs into tumbling pieces.
+pub fn shatter(lander: &Lander, rng: &mut dyn GameRng) -> Vec<Debris> {
+    let body = Lander::BODY;
+    let edges = [[body[0], body[1]], [body[1], body[2]], [body[2], body[0]]];
+    edges
+        .into_iter()
+        .chain(Lander::LEGS)
+        .map(|[a, b]| {
+            let (a, b) = (lander.to_world(a), lander.to_world(b));
+            let out = ((a + b) * 0.5 - lander.pos).normalize_or_zero();
+            let vel = lander.vel * 0.3 + out * rng.range_f32(0.1, 0.3) + Vec2::new(0.0, rng.range_f32(0.05, 0.2));
+            Debris::from_segment(a, b, vel, rng.range_f32(-8.0, 8.0))
+        })
+        .collect()
+}
+
+/// Blow dust sideways from under the flame when it nears the ground.
+pub fn kick_up_dust(dust: &mut Vec<Dust>, lander: &Lander, terrain: &Terrain, rng: &mut dyn GameRng, dt: f32) {
+    if !lander.thrusting || dust.len() >= MAX_DUST {
+        return;
+    }
+    let tip = lander.to_world(lander.flame_tip());
+    let ground = Vec2::new(tip.x, terrain.surface_y_at(tip.x));
+    let height = tip.y - ground.y;
+    if height > DUST_RANGE {
+        return;
+    }
+    let strength = lander.throttle * (1.0 - height.max(0.0) / DUST_RANGE);
+    if rng.range_f32(0.0, 1.0) >= DUST_RATE * strength * dt {
+        return;
+    }
+    for side in [-1.0, 1.0] {
+        dust.push(Dust {
+            pos: ground + Vec2::new(0.0, 0.003),
+            vel: Vec2::new(side * rng.range_f32(0.15, 0.4) * strength, rng.range_f32(0.02, 0.1)),
+            lifetime: rng.range_f32(0.5, 1.0) * Dust::MAX_LIFETIME,
+        });
+    }
+}
+
+/// Upward normal of the terrain at `x`.
+fn surface_normal(terrain: &Terrain, x: f32) -> Vec2 {
+    const H: f32 = 0.005;
+    let slope = (terrain.surface_y_at(x + H) - terrain.surface_y_at(x - H)) / (2.0 * H);
+    Vec2::new(-slope, 1.0).normalize()
+}
@@ -1,61 +1,177 @@
 //! Physics and collision handling for Lunar Lander.
 
-use vectorcade_shared::{game::GameCtx, input::Key};
+use arcade_kit::sound::{SoundEvent, emit};
+use glam::Vec2;
+use vectorcade_shared::{
+    game::AudioOut,
+    input::{Axis, InputState, Key},
+};
 
 use crate::lander::Lander;
 use crate::terrain::Terrain;
 use crate::GameState;
 
-const GRAVITY: f32 = 0.15;
-const THRUST: f32 = 0.35;
+pub(crate) const GRAVITY: f32 = 0.15;
+pub(crate) const THRUST: f32 = 0.35;
 const ROTATION_SPEED: f32 = 2.5;
+/// Fuel per second at full throttle.
 const FUEL_BURN_RATE: f32 = 15.0;
-const SAFE_VELOCITY: f32 = 0.25;
-const SAFE_ANGLE: f32 = 0.3;
-const LANDER_HEIGHT: f32 = 0.05;
+/// Throttle travel per second while Up or Down is held.
+const THROTTLE_RATE: f32 = 1.5;
+/// Stick deflection below which the keys stay in charge.
+const DEADZONE: f32 = 0.15;
+pub(crate) const SAFE_VELOCITY: f32 = 0.25;
+pub(crate) const SAFE_ANGLE: f32 = 0.3;
+/// Below this radar altitude the approach warnings arm.
+const WARN_ALTITUDE: f32 = 0.3;
+/// Radians per second a lander resting on one foot pivots about it.
+const TIP_RATE: f32 = 1.2;
+/// Terrain this far either side of the lander is tested against the hull.
+const REACH: f32 = 0.1;
 
-pub fn update_lander(lander: &mut Lander, ctx: &GameCtx, dt: f32) {
-    // Rotation
-    if ctx.input.key(Key::Left).is_down {
-        lander.angle -= ROTATION_SPEED * dt;
+/// What is being asked of the lander this step, by the pilot or autopilot.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Controls {
+    /// Rotation rate from -1 (full left) to 1 (full right).
+    pub turn: f32,
+    /// Throttle demanded, 0 to 1.
+    pub throttle: f32,
+}
+
+/// Read the pilot's keys and sticks, moving the throttle lever.
+pub fn read_controls(lander: &mut Lander, input: &dyn InputState, dt: f32) -> Controls {
+    // Rotation: a stick steers proportionally, keys at full rate
+    let stick = input.axis(Axis::LeftX);
+    let turn = if stick.abs() > DEADZONE {
+        stick.clamp(-1.0, 1.0)
+    } else {
+        let held = |k: Key| if input.key(k).is_down { 1.0 } else { 0.0 };
+        held(Key::Right) - held(Key::Left)
+    };
+    // Throttle lever: a stick pushed up sets it directly, Up/Down move it
+    let stick = input.axis(Axis::LeftY);
+    if stick.abs() > DEADZONE {
+        lander.lever = stick.clamp(0.0, 1.0);
+    } else {
+        if input.key(Key::Up).is_down {
+            lander.lever += THROTTLE_RATE * dt;
+        }
+        if input.key(Key::Down).is_down {
+            lander.lever -= THROTTLE_RATE * dt;
+        }
+        lander.lever = lander.lever.clamp(0.0, 1.0);
     }
-    if ctx.input.key(Key::Right).is_down {
-        lander.angle += ROTATION_SPEED * dt;
+    // Space overrides the lever with a full burn
+    let throttle = if input.key(Key::Space).is_down { 1.0 } else { lander.lever };
+    Controls { turn, throttle }
+}
+
+pub fn update_lander(lander: &mut Lander, controls: Controls, audio: &dyn AudioOut, dt: f32) {
+    lander.angle += controls.turn.clamp(-1.0, 1.0) * ROTATION_SPEED * dt;
+    lander.throttle = if lander.fuel > 0.0 { controls.throttle.clamp(0.0, 1.0) } else { 0.0 };
+    let thrusting = lander.throttle > 0.0;
+    if thrusting != lander.thrusting {
+        emit(audio, if thrusting { SoundEvent::ThrustStart } else { SoundEvent::ThrustStop });
     }
-    // Thrust
-    let thrust = ctx.input.key(Key::Up).is_down || ctx.input.key(Key::Space).is_down;
-    lander.thrusting = thrust && lander.fuel > 0.0;
+    lander.thrusting = thrusting;
     if lander.thrusting {
-        lander.vel += lander.direction() * THRUST * dt;
-        lander.fuel -= FUEL_BURN_RATE * dt;
+        lander.vel += lander.direction() * THRUST * lander.throttle * dt;
+        lander.fuel -= FUEL_BURN_RATE * lander.throttle * dt;
         lander.fuel = lander.fuel.max(0.0);
     }
     // Gravity
     lander.vel.y -= GRAVITY * dt;
     // Position update
     lander.pos += lander.vel * dt;
-    // Horizontal bounds
-    lander.pos.x = lander.pos.x.clamp(-0.95, 0.95);
 }
 
-pub fn check_landing(lander: &Lander, terrain: &Terrain) -> GameState {
-    let ground_y = terrain.surface_y_at(lander.pos.x);
-    let lander_bottom = lander.pos.y - LANDER_HEIGHT;
-    if lander_bottom > ground_y {
+/// Test the body and both feet against the terrain, pivoting the lander
+/// about a lone foot until the other comes down or it topples.
+pub fn check_landing(lander: &mut Lander, terrain: &Terrain, dt: f32) -> GameState {
+    if hull_touches(lander, terrain) {
+        return GameState::Crashed;
+    }
+    let feet = lander.feet();
+    let down = feet.map(|f| f.y <= terrain.surface_y_at(f.x));
+    match down {
+        [false, false] => GameState::Playing,
+        [true, true] => {
+            // Both feet on the same pad, slowly and level
+            let same_pad = matches!(feet.map(|f| terrain.pad_at(f.x)), [Some(a), Some(b)] if std::ptr::eq(a, b));
+            let safe_vel = lander.vel.length() < SAFE_VELOCITY;
+            let safe_angle = lander.angle.abs() < SAFE_ANGLE;
+            if same_pad && safe_vel && safe_angle { GameState::Landed } else { GameState::Crashed }
+        }
+        [left, _] => tip(lander, feet[if left { 0 } else { 1 }], terrain, dt),
+    }
+}
+
+/// Rest on one foot: the lander swings down on whichever side of it the
+/// centre of mass lies, settling onto the other foot or toppling.
+fn tip(lander: &mut Lander, foot: Vec2, terrain: &Terrain, dt: f32) -> GameState {
+    if lander.vel.y > 0.0 {
+        // Climbing away under thrust
         return GameState::Playing;
     }
-    // Check if on landing pad with safe velocity and angle
-    let on_pad = terrain.pad_at(lander.pos.x).is_some();
-    let safe_vel = lander.vel.length() < SAFE_VELOCITY;
-    let safe_angle = lander.angle.abs() < SAFE_ANGLE;
-    if on_pad && safe_vel && safe_angle {
-        GameState::Landed
+    if lander.vel.length() >= SAFE_VELOCITY || terrain.pad_at(foot.x).is_none() {
+        return GameState::Crashed;
+    }
+    lander.vel = Vec2::ZERO;
+    // A positive angle lifts the right side
+    let delta = if lander.pos.x > foot.x { -TIP_RATE * dt } else { TIP_RATE * dt };
+    let (s, c) = delta.sin_cos();
+    let arm = lander.pos - foot;
+    lander.pos = foot + Vec2::new(arm.x * c - arm.y * s, arm.x * s + arm.y * c);
+    lander.angle += delta;
+    if lander.angle.abs() > SAFE_ANGLE { GameState::Crashed } else { GameState::Playing }
+}
+
+/// Whether the body triangle has met the ground, either by a corner
+/// dipping below it or by a peak poking through an edge.
+fn hull_touches(lander: &Lander, terrain: &Terrain) -> bool {
+    let hull = lander.hull();
+    if hull.iter().any(|p| p.y < terrain.surface_y_at(p.x)) {
+        return true;
+    }
+    let edges = [(hull[0], hull[1]), (hull[1], hull[2]), (hull[2], hull[0])];
+    terrain.points.windows(2).any(|seg| {
+        let span = seg[1] - seg[0];
+        let dx = terrain.wrap_dx(lander.pos.x, seg[0].x);
+        if dx > REACH || dx + span.x < -REACH {
+            return false;
+        }
+        let a = Vec2::new(lander.pos.x + dx, seg[0].y);
+        edges.iter().any(|&(p, q)| segments_cross(p, q, a, a + span))
+    })
+}
+
+fn segments_cross(a1: Vec2, a2: Vec2, b1: Vec2, b2: Vec2) -> bool {
+    let side = |o: Vec2, p: Vec2, q: Vec2| (p - o).perp_dot(q - o);
+    let (d1, d2) = (side(b1, b2, a1), side(b1, b2, a2));
+    let (d3, d4) = (side(a1, a2, b1), side(a1, a2, b2));
+    d1 * d2 < 0.0 && d3 * d4 < 0.0
+}
+
+/// Height of the lower foot above the ground beneath it.
+pub fn radar_altitude(lander: &Lander, terrain: &Terrain) -> f32 {
+    let [left, right] = lander.feet().map(|f| f.y - terrain.surface_y_at(f.x));
+    left.min(right)
+}
+
+/// What would make touching down now a crash, if the ground is close.
+pub fn approach_warning(lander: &Lander, terrain: &Terrain) -> Option<&'static str> {
+    if radar_altitude(lander, terrain) > WARN_ALTITUDE {
+        None
+    } else if lander.vel.length() >= SAFE_VELOCITY {
+        Some("TOO FAST")
+    } else if lander.angle.abs() >= SAFE_ANGLE {
+        Some("TOO STEEP")
     } else {
-        GameState::Crashed
+        None
     }
 }
 
-pub fn calculate_score(lander: &Lander) -> u32 {
+pub fn calculate_score(lander: &Lander, multiplier: u32) -> u32 {
     let fuel_bonus = (lander.fuel * 10.0) as u32;
-    100 + fuel_bonus
+    (100 + fuel_bonus) * multiplier
 }
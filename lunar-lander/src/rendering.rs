@@ -1,5 +1,6 @@
 //! Rendering functions for Lunar Lander.
 
+use arcade_kit::debris::Debris;
 use glam::Vec2;
 use vectorcade_shared::{
     Rgba,
@@ -7,70 +8,104 @@ use vectorcade_shared::{
     font::FontStyleId,
 };
 
+use crate::autopilot::AutopilotMode;
+use crate::camera::Camera;
 use crate::lander::Lander;
+use crate::particles::Dust;
 use crate::terrain::Terrain;
 
-const WHITE: Rgba = Rgba::WHITE;
+pub(crate) const WHITE: Rgba = Rgba::WHITE;
+/// Half the widest screen we expect, in screen units; anything beyond is culled.
+const VIEW_HALF_WIDTH: f32 = 1.4;
 
-pub fn render_terrain(out: &mut Vec<DrawCmd>, terrain: &Terrain) {
-    // Draw terrain surface
-    out.push(DrawCmd::Polyline {
-        pts: terrain.points.clone(),
-        closed: false,
-        stroke: Stroke::new(WHITE, 1.5),
-    });
+pub fn render_terrain(out: &mut Vec<DrawCmd>, terrain: &Terrain, camera: &Camera) {
+    // Draw terrain surface, one polyline per run of visible segments
+    let mut run: Vec<Vec2> = Vec::new();
+    for seg in terrain.points.windows(2) {
+        let a = camera.to_screen(seg[0], terrain);
+        let b = a + (seg[1] - seg[0]) * camera.zoom;
+        if !on_screen(a.x, b.x) {
+            flush_run(out, &mut run);
+            continue;
+        }
+        // The seam can land mid-run; start afresh when the ends don't meet
+        if run.last().is_some_and(|&last| last.distance(a) > 1e-4) {
+            flush_run(out, &mut run);
+        }
+        if run.is_empty() {
+            run.push(a);
+        }
+        run.push(b);
+    }
+    flush_run(out, &mut run);
     // Draw landing pads (thicker line to stand out)
     for pad in &terrain.pads {
-        out.push(DrawCmd::Polyline {
-            pts: vec![Vec2::new(pad.x_min, pad.y), Vec2::new(pad.x_max, pad.y)],
-            closed: false,
-            stroke: Stroke::new(WHITE, 3.0),
-        });
+        let a = camera.to_screen(Vec2::new(pad.x_min, pad.y), terrain);
+        let b = a + Vec2::new((pad.x_max - pad.x_min) * camera.zoom, 0.0);
+        if !on_screen(a.x, b.x) {
+            continue;
+        }
+        out.push(DrawCmd::Polyline { pts: vec![a, b], closed: false, stroke: Stroke::new(WHITE, 3.0) });
         // Multiplier indicator
-        let mid_x = (pad.x_min + pad.x_max) / 2.0;
+        let mid_x = (a.x + b.x) / 2.0;
         out.push(DrawCmd::Text {
-            pos: Vec2::new(mid_x - 0.02, pad.y - 0.05),
+            pos: Vec2::new(mid_x - 0.02, a.y - 0.05),
             text: format!("x{}", pad.multiplier),
-            size_px: 10.0,
+            size_px: 10.0 * camera.zoom,
             color: WHITE,
             style: FontStyleId::ATARI,
         });
     }
 }
 
-pub fn render_lander(out: &mut Vec<DrawCmd>, lander: &Lander) {
-    let (s, c) = (lander.angle.sin(), lander.angle.cos());
-    let rotate = |v: Vec2| Vec2::new(v.x * c - v.y * s, v.x * s + v.y * c);
+/// Whether a span of screen x overlaps the view, with room for wide aspects.
+fn on_screen(x0: f32, x1: f32) -> bool {
+    x0.min(x1) <= VIEW_HALF_WIDTH && x0.max(x1) >= -VIEW_HALF_WIDTH
+}
+
+fn flush_run(out: &mut Vec<DrawCmd>, run: &mut Vec<Vec2>) {
+    if run.len() >= 2 {
+        out.push(DrawCmd::Polyline { pts: std::mem::take(run), closed: false, stroke: Stroke::new(WHITE, 1.5) });
+    }
+    run.clear();
+}
+
+pub fn render_lander(out: &mut Vec<DrawCmd>, lander: &Lander, terrain: &Terrain, camera: &Camera) {
+    let origin = camera.to_screen(lander.pos, terrain);
+    let at = |v: Vec2| origin + (lander.to_world(v) - lander.pos) * camera.zoom;
     // Lander body (triangular with legs)
-    let body = vec![
-        lander.pos + rotate(Vec2::new(0.0, 0.04)),
-        lander.pos + rotate(Vec2::new(-0.025, -0.02)),
-        lander.pos + rotate(Vec2::new(0.025, -0.02)),
-    ];
+    let body = Lander::BODY.map(at).to_vec();
     out.push(DrawCmd::Polyline { pts: body, closed: true, stroke: Stroke::new(WHITE, 2.0) });
     // Landing legs
-    let leg_l = vec![
-        lander.pos + rotate(Vec2::new(-0.02, -0.02)),
-        lander.pos + rotate(Vec2::new(-0.035, -0.05)),
-    ];
-    let leg_r = vec![
-        lander.pos + rotate(Vec2::new(0.02, -0.02)),
-        lander.pos + rotate(Vec2::new(0.035, -0.05)),
-    ];
-    out.push(DrawCmd::Polyline { pts: leg_l, closed: false, stroke: Stroke::new(WHITE, 1.5) });
-    out.push(DrawCmd::Polyline { pts: leg_r, closed: false, stroke: Stroke::new(WHITE, 1.5) });
+    for leg in Lander::LEGS {
+        out.push(DrawCmd::Polyline { pts: leg.map(at).to_vec(), closed: false, stroke: Stroke::new(WHITE, 1.5) });
+    }
     // Thrust flame
     if lander.thrusting {
-        let flame = vec![
-            lander.pos + rotate(Vec2::new(-0.012, -0.02)),
-            lander.pos + rotate(Vec2::new(0.0, -0.07)),
-            lander.pos + rotate(Vec2::new(0.012, -0.02)),
-        ];
+        // The flame grows with the throttle
+        let [left, _, right] = Lander::FLAME;
+        let flame = vec![at(left), at(lander.flame_tip()), at(right)];
         out.push(DrawCmd::Polyline { pts: flame, closed: false, stroke: Stroke::new(WHITE, 2.0) });
     }
 }
 
-pub fn render_hud(out: &mut Vec<DrawCmd>, lander: &Lander, style: FontStyleId) {
+pub fn render_debris(out: &mut Vec<DrawCmd>, debris: &Debris, terrain: &Terrain, camera: &Camera) {
+    let (a, b) = debris.endpoints();
+    let start = camera.to_screen(a, terrain);
+    let pts = vec![start, start + (b - a) * camera.zoom];
+    out.push(DrawCmd::Polyline { pts, closed: false, stroke: Stroke::new(WHITE, 2.0) });
+}
+
+pub fn render_dust(out: &mut Vec<DrawCmd>, dust: &Dust, terrain: &Terrain, camera: &Camera) {
+    let p = camera.to_screen(dust.pos, terrain);
+    out.push(DrawCmd::Polyline {
+        pts: vec![p, p + Vec2::new(0.004, 0.0)],
+        closed: false,
+        stroke: Stroke::new(WHITE.with_a(dust.alpha()), 2.0),
+    });
+}
+
+pub fn render_hud(out: &mut Vec<DrawCmd>, lander: &Lander, score: u32, round: u32, style: FontStyleId) {
     out.push(DrawCmd::Text {
         pos: Vec2::new(-0.95, 0.9),
         text: format!("FUEL: {:3.0}", lander.fuel),
@@ -78,23 +113,56 @@ pub fn render_hud(out: &mut Vec<DrawCmd>, lander: &Lander, style: FontStyleId) {
         color: WHITE,
         style,
     });
+    render_throttle(out, lander.throttle, style);
     out.push(DrawCmd::Text {
         pos: Vec2::new(-0.95, 0.75),
-        text: format!("VEL: {:.2}", lander.vel.length()),
+        text: format!("SCORE: {score}"),
         size_px: 48.0,
         color: WHITE,
         style,
     });
     out.push(DrawCmd::Text {
-        pos: Vec2::new(0.35, 0.9),
-        text: format!("ALT: {:.2}", lander.pos.y + 0.9),
+        pos: Vec2::new(-0.95, 0.42),
+        text: format!("ROUND: {round}"),
         size_px: 48.0,
         color: WHITE,
         style,
     });
 }
 
-pub fn render_landed(out: &mut Vec<DrawCmd>, score: u32, style: FontStyleId) {
+/// Throttle gauge: a labelled bar filled to the delivered throttle.
+fn render_throttle(out: &mut Vec<DrawCmd>, throttle: f32, style: FontStyleId) {
+    const ORIGIN: Vec2 = Vec2::new(-0.95, 0.56);
+    const SIZE: Vec2 = Vec2::new(0.4, 0.05);
+    out.push(DrawCmd::Text {
+        pos: ORIGIN + Vec2::new(0.0, 0.08),
+        text: format!("THR: {:3.0}%", throttle * 100.0),
+        size_px: 40.0,
+        color: WHITE,
+        style,
+    });
+    let frame = vec![ORIGIN, ORIGIN + Vec2::new(SIZE.x, 0.0), ORIGIN + SIZE, ORIGIN + Vec2::new(0.0, SIZE.y)];
+    out.push(DrawCmd::Polyline { pts: frame, closed: true, stroke: Stroke::new(WHITE, 1.0) });
+    if throttle > 0.0 {
+        let mid = ORIGIN + Vec2::new(0.0, SIZE.y / 2.0);
+        let fill = vec![mid, mid + Vec2::new(SIZE.x * throttle, 0.0)];
+        out.push(DrawCmd::Polyline { pts: fill, closed: false, stroke: Stroke::new(WHITE, 6.0) });
+    }
+}
+
+/// Seed of a generated layout, small in the corner, for replaying it.
+pub fn render_seed(out: &mut Vec<DrawCmd>, seed: Option<u64>, style: FontStyleId) {
+    let Some(seed) = seed else { return };
+    out.push(DrawCmd::Text {
+        pos: Vec2::new(-0.95, -0.95),
+        text: format!("SEED {seed}"),
+        size_px: 28.0,
+        color: WHITE,
+        style,
+    });
+}
+
+pub fn render_landed(out: &mut Vec<DrawCmd>, points: u32, style: FontStyleId) {
     out.push(DrawCmd::Text {
         pos: Vec2::new(-0.25, 0.1),
         text: "LANDED!".to_string(),
@@ -104,31 +172,78 @@ pub fn render_landed(out: &mut Vec<DrawCmd>, score: u32, style: FontStyleId) {
     });
     out.push(DrawCmd::Text {
         pos: Vec2::new(-0.2, -0.05),
-        text: format!("SCORE: {}", score),
+        text: format!("+{points} POINTS"),
         size_px: 18.0,
         color: WHITE,
         style,
     });
 }
 
-pub fn render_crashed(out: &mut Vec<DrawCmd>, style: FontStyleId) {
+pub fn render_crashed(out: &mut Vec<DrawCmd>, fuel_lost: f32, style: FontStyleId) {
     out.push(DrawCmd::Text {
-        pos: Vec2::new(-0.25, 0.0),
+        pos: Vec2::new(-0.25, 0.1),
         text: "CRASHED!".to_string(),
         size_px: 24.0,
         color: WHITE,
         style,
     });
+    out.push(DrawCmd::Text {
+        pos: Vec2::new(-0.2, -0.05),
+        text: format!("-{fuel_lost:.0} FUEL"),
+        size_px: 18.0,
+        color: WHITE,
+        style,
+    });
+}
+
+/// End-of-campaign summary; `tally` is rounds flown, landings and crashes.
+pub fn render_summary(out: &mut Vec<DrawCmd>, score: u32, tally: (u32, u32, u32), blink_timer: f32, style: FontStyleId) {
+    let (rounds, landings, crashes) = tally;
+    let lines = [
+        ("OUT OF FUEL".to_string(), -0.45, 0.55, 80.0),
+        (format!("SCORE: {score}"), -0.40, 0.25, 56.0),
+        (format!("ROUNDS: {rounds}"), -0.40, 0.05, 48.0),
+        (format!("LANDINGS: {landings}"), -0.40, -0.12, 48.0),
+        (format!("CRASHES: {crashes}"), -0.40, -0.29, 48.0),
+    ];
+    for (text, x, y, size) in lines {
+        out.push(DrawCmd::Text { pos: Vec2::new(x, y), text, size_px: size, color: WHITE, style });
+    }
+    if (blink_timer % 1.0) < 0.6 {
+        out.push(DrawCmd::Text {
+            pos: Vec2::new(-0.70, -0.65), text: "PRESS SPACE TO PLAY AGAIN".to_string(), size_px: 48.0, color: WHITE, style,
+        });
+    }
+}
+
+pub fn render_autopilot(out: &mut Vec<DrawCmd>, mode: AutopilotMode, style: FontStyleId) {
+    let label = match mode {
+        AutopilotMode::Full => "AUTOPILOT",
+        AutopilotMode::Assist => "DESCENT ASSIST",
+    };
+    out.push(DrawCmd::Text { pos: Vec2::new(-0.95, 0.3), text: label.to_string(), size_px: 40.0, color: WHITE, style });
+}
+
+/// Caption over the attract-mode demo flight.
+pub fn render_demo(out: &mut Vec<DrawCmd>, style: FontStyleId, blink_timer: f32) {
+    out.push(DrawCmd::Text {
+        pos: Vec2::new(-0.15, 0.8), text: "DEMO".to_string(), size_px: 64.0, color: WHITE, style,
+    });
+    if (blink_timer % 1.0) < 0.6 {
+        out.push(DrawCmd::Text {
+            pos: Vec2::new(-0.70, 0.6), text: "PRESS SPACE TO START".to_string(), size_px: 56.0, color: WHITE, style,
+        });
+    }
 }
 
 pub fn render_instructions(out: &mut Vec<DrawCmd>, style: FontStyleId, blink_timer: f32) {
     let lines = [
         ("LUNAR LANDER", -0.50, 0.65, 96.0),
         ("LEFT/RIGHT - ROTATE", -0.70, 0.35, 48.0),
-        ("UP OR SPACE - THRUST", -0.72, 0.15, 48.0),
-        ("LAND ON FLAT PADS", -0.62, -0.05, 48.0),
+        ("UP/DOWN - THROTTLE, SPACE - FULL", -0.92, 0.15, 44.0),
+        ("A - DESCENT ASSIST", -0.62, -0.05, 48.0),
         ("LAND SLOWLY AND LEVEL", -0.75, -0.25, 48.0),
-        ("CONSERVE FUEL FOR BONUS", -0.82, -0.45, 48.0),
+        ("FUEL CARRIES OVER, CRASHES COST FUEL", -0.95, -0.45, 40.0),
     ];
     for (text, x, y, size) in lines {
         out.push(DrawCmd::Text {
@@ -0,0 +1,99 @@
+//! Procedural terrain: rolling ground with two to four landing pads.
+
+use glam::Vec2;
+use vectorcade_shared::{GameRng, Xorshift64};
+
+use super::{LandingPad, Terrain};
+
+const MIN_PADS: usize = 2;
+const MAX_PADS: usize = 4;
+const FLOOR: f32 = -0.9;
+const CEILING: f32 = -0.3;
+/// Room kept clear before the seam so it can be closed off smoothly.
+const SEAM: f32 = 0.15;
+/// Pads stay this far from the seam.
+const MARGIN: f32 = 0.3;
+
+impl Terrain {
+    /// Generate a fresh layout. `difficulty` runs from 0 (gentle hills,
+    /// wide pads) to 1 (rough ground, mostly narrow pads on peaks and in
+    /// valleys).
+    pub fn generate(rng: &mut dyn GameRng, difficulty: f32) -> Self {
+        let difficulty = difficulty.clamp(0.0, 1.0);
+        let width = 2.0 * Self::SCREENS as f32;
+        let half = width / 2.0;
+
+        // Rolling ground: a random walk that gets rougher with difficulty
+        let roughness = 0.1 + 0.1 * difficulty;
+        let start_y = rng.range_f32(-0.7, -0.5);
+        let (mut x, mut y) = (-half, start_y);
+        let mut points = vec![Vec2::new(x, y)];
+        while x < half - SEAM {
+            x = (x + rng.range_f32(0.08, 0.15)).min(half - SEAM);
+            y = (y + rng.range_f32(-roughness, roughness)).clamp(FLOOR, CEILING);
+            points.push(Vec2::new(x, y));
+        }
+        points.push(Vec2::new(half, start_y));
+        let mut terrain = Self { points, pads: Vec::new(), width, seed: None };
+
+        // One pad per sector so they spread across the world
+        let count = MIN_PADS + rng.pick_index(MAX_PADS - MIN_PADS + 1).unwrap_or(0);
+        let sector = (width - 2.0 * MARGIN) / count as f32;
+        for i in 0..count {
+            let lo = -half + MARGIN + sector * i as f32;
+            let pad = if rng.range_f32(0.0, 1.0) < 0.25 + 0.5 * difficulty {
+                terrain.hard_pad(lo, lo + sector, difficulty, rng)
+            } else {
+                terrain.easy_pad(lo, lo + sector, difficulty, rng)
+            };
+            terrain.flatten(pad);
+        }
+        terrain
+    }
+
+    /// Generate the layout printed as `seed`; the same seed and difficulty
+    /// always give the same terrain.
+    pub fn from_seed(seed: u64, difficulty: f32) -> Self {
+        let mut rng = Xorshift64::new(seed);
+        Self { seed: Some(seed), ..Self::generate(&mut rng, difficulty) }
+    }
+
+    /// A wide pad wherever it happens to fall in `lo..hi`.
+    fn easy_pad(&self, lo: f32, hi: f32, difficulty: f32, rng: &mut dyn GameRng) -> LandingPad {
+        let width = rng.range_f32(0.16, 0.22) * (1.0 - 0.25 * difficulty);
+        let mid = rng.range_f32(lo + width / 2.0, hi - width / 2.0);
+        LandingPad {
+            x_min: mid - width / 2.0,
+            x_max: mid + width / 2.0,
+            y: self.surface_y_at(mid),
+            multiplier: if width < 0.17 { 2 } else { 1 },
+        }
+    }
+
+    /// A narrow pad at the lowest or highest point of `lo..hi`.
+    fn hard_pad(&self, lo: f32, hi: f32, difficulty: f32, rng: &mut dyn GameRng) -> LandingPad {
+        let width = rng.range_f32(0.11, 0.13) - 0.02 * difficulty;
+        let peak = rng.pick_index(2) == Some(0);
+        let inside = self.points.iter().filter(|p| p.x > lo + width / 2.0 && p.x < hi - width / 2.0);
+        let spot = if peak {
+            inside.max_by(|a, b| a.y.total_cmp(&b.y))
+        } else {
+            inside.min_by(|a, b| a.y.total_cmp(&b.y))
+        };
+        let spot = spot.copied().unwrap_or(Vec2::new((lo + hi) / 2.0, self.surface_y_at((lo + hi) / 2.0)));
+        LandingPad {
+            x_min: spot.x - width / 2.0,
+            x_max: spot.x + width / 2.0,
+            y: spot.y,
+            multiplier: if peak { 5 } else { 3 },
+        }
+    }
+
+    /// Level the ground under `pad` and add it.
+    fn flatten(&mut self, pad: LandingPad) {
+        let start = self.points.partition_point(|p| p.x < pad.x_min);
+        let end = self.points.partition_point(|p| p.x <= pad.x_max);
+        self.points.splice(start..end, [Vec2::new(pad.x_min, pad.y), Vec2::new(pad.x_max, pad.y)]);
+        self.pads.push(pad);
+    }
+}
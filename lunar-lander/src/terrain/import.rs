@@ -0,0 +1,109 @@
+//! Hand-authored terrain in a small line-based text format:
+//!
+//! ```text
+//! # Comments and blank lines are ignored.
+//! point -3.0 -0.6    # surface, left to right, from -width/2 to width/2,
+//!                    # ending at the height it started
+//! point -0.2 -0.5
+//! point  0.2 -0.5
+//! point  3.0 -0.6
+//! pad -0.2 0.2 2     # x_min x_max multiplier, on flat ground, not overlapping
+//! ```
+
+use std::fmt;
+
+use glam::Vec2;
+
+use super::{LandingPad, Terrain};
+
+/// Tolerance when checking coordinates line up.
+const EPSILON: f32 = 1e-4;
+
+/// Reasons a terrain file can't be used. Line numbers count from 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TerrainError {
+    /// Unknown keyword or malformed numbers.
+    BadLine(usize),
+    TooFewPoints,
+    /// A point left of the one before it.
+    Unordered(usize),
+    /// The points don't span an interval centred on x = 0.
+    NotCentred,
+    /// The last point isn't level with the first, so the wrap seam is a cliff.
+    SeamMismatch,
+    /// A pad that doesn't lie on flat ground.
+    PadNotFlat(usize),
+    /// A pad overlapping one declared earlier.
+    PadsOverlap(usize),
+}
+
+impl fmt::Display for TerrainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadLine(n) => write!(f, "line {}: expected `point x y` or `pad x_min x_max multiplier`", n),
+            Self::TooFewPoints => write!(f, "terrain needs at least two points"),
+            Self::Unordered(n) => write!(f, "line {}: points must run left to right", n),
+            Self::NotCentred => write!(f, "points must run from -width/2 to width/2"),
+            Self::SeamMismatch => write!(f, "first and last points must be at the same height"),
+            Self::PadNotFlat(n) => write!(f, "line {}: pad is not on flat ground", n),
+            Self::PadsOverlap(n) => write!(f, "line {}: pad overlaps an earlier pad", n),
+        }
+    }
+}
+
+impl std::error::Error for TerrainError {}
+
+impl Terrain {
+    /// Read terrain in the format described in this module.
+    pub fn parse(text: &str) -> Result<Self, TerrainError> {
+        let mut points: Vec<Vec2> = Vec::new();
+        let mut pads = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let n = i + 1;
+            let line = line.split('#').next().unwrap_or("");
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let num = |w: &str| w.parse::<f32>().ok().filter(|v| v.is_finite()).ok_or(TerrainError::BadLine(n));
+            match words.as_slice() {
+                [] => {}
+                ["point", x, y] => {
+                    let p = Vec2::new(num(x)?, num(y)?);
+                    if points.last().is_some_and(|last| p.x <= last.x) {
+                        return Err(TerrainError::Unordered(n));
+                    }
+                    points.push(p);
+                }
+                ["pad", x_min, x_max, multiplier] => {
+                    let multiplier = multiplier.parse().ok().filter(|&m| m > 0).ok_or(TerrainError::BadLine(n))?;
+                    pads.push((n, num(x_min)?, num(x_max)?, multiplier));
+                }
+                _ => return Err(TerrainError::BadLine(n)),
+            }
+        }
+        if points.len() < 2 {
+            return Err(TerrainError::TooFewPoints);
+        }
+        let (first, last) = (points[0], points[points.len() - 1]);
+        if (first.x + last.x).abs() > EPSILON {
+            return Err(TerrainError::NotCentred);
+        }
+        if (first.y - last.y).abs() > EPSILON {
+            return Err(TerrainError::SeamMismatch);
+        }
+        let mut terrain = Self { points, pads: Vec::new(), width: last.x - first.x, seed: None };
+        for (n, x_min, x_max, multiplier) in pads {
+            let y = terrain.surface_y_at(x_min);
+            let level = |h: f32| (h - y).abs() < EPSILON;
+            let flat = x_max > x_min
+                && level(terrain.surface_y_at(x_max))
+                && terrain.points.iter().filter(|p| p.x > x_min && p.x < x_max).all(|p| level(p.y));
+            if !flat {
+                return Err(TerrainError::PadNotFlat(n));
+            }
+            if terrain.pads.iter().any(|p| x_min < p.x_max && p.x_min < x_max) {
+                return Err(TerrainError::PadsOverlap(n));
+            }
+            terrain.pads.push(LandingPad { x_min, x_max, y, multiplier });
+        }
+        Ok(terrain)
+    }
+}
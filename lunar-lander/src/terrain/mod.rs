@@ -0,0 +1,73 @@
+//! Terrain for Lunar Lander.
+
+mod generator;
+mod import;
+
+pub use import::TerrainError;
+
+use glam::Vec2;
+
+/// Landing pad on the terrain.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LandingPad {
+    pub x_min: f32,
+    pub x_max: f32,
+    pub y: f32,
+    pub multiplier: u32,
+}
+
+/// Terrain with surface points and landing pads.
+///
+/// The surface spans `x` in `[-width / 2, width / 2]` and wraps: the last
+/// point sits at the same height as the first, and lookups fold `x` back
+/// into range.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Terrain {
+    pub points: Vec<Vec2>,
+    pub pads: Vec<LandingPad>,
+    pub width: f32,
+    /// Seed that regenerates this layout, if it was generated.
+    pub seed: Option<u64>,
+}
+
+impl Terrain {
+    /// Screens of terrain generated side by side.
+    pub const SCREENS: usize = 3;
+
+    pub fn new() -> Self {
+        Self { points: Vec::new(), pads: Vec::new(), width: 2.0 * Self::SCREENS as f32, seed: None }
+    }
+
+    /// Fold `x` into the terrain's span.
+    pub fn wrap_x(&self, x: f32) -> f32 {
+        let half = self.width / 2.0;
+        (x + half).rem_euclid(self.width) - half
+    }
+
+    /// Signed horizontal offset from `from` to `to` the short way round.
+    pub fn wrap_dx(&self, from: f32, to: f32) -> f32 {
+        self.wrap_x(to - from)
+    }
+
+    pub fn surface_y_at(&self, x: f32) -> f32 {
+        let x = self.wrap_x(x);
+        for i in 0..self.points.len().saturating_sub(1) {
+            let p1 = self.points[i];
+            let p2 = self.points[i + 1];
+            if x >= p1.x && x <= p2.x {
+                let t = (x - p1.x) / (p2.x - p1.x);
+                return p1.y + t * (p2.y - p1.y);
+            }
+        }
+        -0.9
+    }
+
+    pub fn pad_at(&self, x: f32) -> Option<&LandingPad> {
+        let x = self.wrap_x(x);
+        self.pads.iter().find(|p| x >= p.x_min && x <= p.x_max)
+    }
+}
+
+impl Default for Terrain {
+    fn default() -> Self { Self::new() }
+}
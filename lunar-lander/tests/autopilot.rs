@@ -0,0 +1,129 @@
+use glam::Vec2;
+use lunar_lander::{Autopilot, AutopilotMode, GameState, LandingPad, LunarLander, Terrain};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+/// Holds one key down, reporting the press on every step.
+struct Tap(Key);
+impl InputState for Tap {
+    fn key(&self, k: Key) -> Button {
+        if k == self.0 { Button { is_down: true, went_down: true, went_up: false } } else { Button::UP }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+/// Flat ground at y = -0.5 with one pad around x = 0.
+fn flat() -> Terrain {
+    Terrain {
+        points: vec![Vec2::new(-3.0, -0.5), Vec2::new(3.0, -0.5)],
+        pads: vec![LandingPad { x_min: -0.2, x_max: 0.2, y: -0.5, multiplier: 1 }],
+        width: 6.0,
+        seed: None,
+    }
+}
+
+/// Let `mode` fly a fresh lander over `terrain` until it touches down or
+/// `seconds` pass; returns the state it ends in and the game.
+fn flight(terrain: Terrain, mode: AutopilotMode, seconds: f32) -> (GameState, LunarLander) {
+    let input = NoInput;
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(1);
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = LunarLander::new();
+    g.reset(&mut ctx);
+    g.state = GameState::Playing;
+    g.terrain = terrain;
+    g.autopilot = Some(Autopilot::new(mode));
+    for _ in 0..(seconds / 0.25) as usize {
+        g.update(&mut ctx, 0.25);
+        if g.state != GameState::Playing {
+            break;
+        }
+    }
+    (g.state, g)
+}
+
+#[test]
+fn full_auto_lands_on_generated_terrain() {
+    for difficulty in [0.0, 0.5, 1.0] {
+        let landed = (1..=100)
+            .filter(|&seed| flight(Terrain::from_seed(seed, difficulty), AutopilotMode::Full, 60.0).0 == GameState::Landed)
+            .count();
+        assert!(landed >= 95, "difficulty {}: only {} of 100 seeds landed", difficulty, landed);
+    }
+}
+
+#[test]
+fn full_auto_targets_nearest_pad() {
+    let mut terrain = flat();
+    terrain.pads.push(LandingPad { x_min: 1.5, x_max: 1.7, y: -0.5, multiplier: 5 });
+    let (state, g) = flight(terrain, AutopilotMode::Full, 60.0);
+    assert!(state == GameState::Landed);
+    assert!(g.lander.pos.x.abs() < 0.2, "landed at {}", g.lander.pos.x);
+}
+
+#[test]
+fn assist_keeps_a_hands_off_drop_survivable() {
+    // Pad all the way round, so wherever the drift takes it is fair
+    let mut terrain = flat();
+    terrain.pads[0] = LandingPad { x_min: -3.0, x_max: 3.0, y: -0.5, multiplier: 1 };
+    let (state, g) = flight(terrain, AutopilotMode::Assist, 60.0);
+    assert!(state == GameState::Landed);
+    assert!(g.lander.fuel > 0.0);
+}
+
+#[test]
+fn a_key_toggles_descent_assist() {
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(1);
+    let mut ctx = GameCtx { input: &NoInput, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = LunarLander::new();
+    g.reset(&mut ctx);
+    g.state = GameState::Playing;
+    // One press per 60 Hz frame, each frame running more than one fixed step
+    let mut press = |g: &mut LunarLander| {
+        ctx.input = &Tap(Key::A);
+        g.update(&mut ctx, 1.0 / 60.0);
+        ctx.input = &NoInput;
+        g.update(&mut ctx, 1.0 / 60.0);
+    };
+    press(&mut g);
+    assert_eq!(g.autopilot, Some(Autopilot::new(AutopilotMode::Assist)));
+    press(&mut g);
+    assert_eq!(g.autopilot, None);
+}
+
+#[test]
+fn attract_mode_flies_the_demo_until_space() {
+    let input = NoInput;
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(1);
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = LunarLander::new();
+    g.reset(&mut ctx);
+    let start = g.lander.pos;
+    for _ in 0..8 {
+        g.update(&mut ctx, 0.25);
+    }
+    assert!(g.state == GameState::Instructions);
+    assert_ne!(g.lander.pos, start);
+
+    let input = Tap(Key::Space);
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    g.update(&mut ctx, 1.0 / 120.0);
+    assert!(g.state == GameState::Playing);
+    assert_eq!(g.round, 1);
+    assert_eq!(g.score, 0);
+}
@@ -0,0 +1,104 @@
+use glam::Vec2;
+use lunar_lander::{Camera, GameState, LunarLander, Terrain};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::draw::DrawCmd;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+fn with_game(seed: u64, f: impl FnOnce(&mut LunarLander, &mut GameCtx)) {
+    let input = NoInput;
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(seed);
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = LunarLander::new();
+    g.reset(&mut ctx);
+    g.state = GameState::Playing;
+    f(&mut g, &mut ctx);
+}
+
+#[test]
+fn terrain_spans_several_screens_and_wraps() {
+    with_game(7, |g, _| {
+        let t = &g.terrain;
+        assert!(t.width >= 4.0, "terrain should be wider than one screen");
+        let (first, last) = (t.points[0], *t.points.last().unwrap());
+        assert_eq!(first.x, -t.width / 2.0);
+        assert_eq!(last.x, t.width / 2.0);
+        assert_eq!(first.y, last.y, "ends meet so the seam is invisible");
+        for x in [-2.7, -0.4, 1.3, 2.9] {
+            assert!((t.surface_y_at(x) - t.surface_y_at(x + t.width)).abs() < 1e-4);
+        }
+    });
+}
+
+#[test]
+fn lander_wraps_around_the_world() {
+    with_game(7, |g, ctx| {
+        let half = g.terrain.width / 2.0;
+        g.lander.pos = Vec2::new(half - 0.01, 0.7);
+        g.lander.vel = Vec2::new(0.5, 0.0);
+        g.update(ctx, 0.1);
+        assert!(g.lander.pos.x < -half + 0.1, "x = {}", g.lander.pos.x);
+        assert!(g.state == GameState::Playing);
+    });
+}
+
+#[test]
+fn camera_follows_and_zooms_near_the_ground() {
+    with_game(7, |g, ctx| {
+        g.lander.pos = Vec2::new(1.5, 0.7);
+        g.lander.vel = Vec2::ZERO;
+        g.update(ctx, 0.01);
+        assert!(!g.camera.is_close());
+        assert!((g.camera.center.x - g.lander.pos.x).abs() < 1e-6);
+
+        let x = 1.5;
+        g.lander.pos = Vec2::new(x, g.terrain.surface_y_at(x) + Camera::ZOOM_ALTITUDE * 0.5);
+        g.lander.vel = Vec2::ZERO;
+        g.update(ctx, 0.01);
+        assert!(g.camera.is_close());
+        assert_eq!(g.camera.zoom, Camera::CLOSE_ZOOM);
+
+        // Just above the threshold still stays close; well above zooms back out
+        g.lander.pos.y = g.terrain.surface_y_at(g.lander.pos.x) + Camera::ZOOM_ALTITUDE * 1.1;
+        g.update(ctx, 0.01);
+        assert!(g.camera.is_close());
+        g.lander.pos.y = g.terrain.surface_y_at(g.lander.pos.x) + Camera::ZOOM_ALTITUDE * 2.0;
+        g.update(ctx, 0.01);
+        assert!(!g.camera.is_close());
+    });
+}
+
+#[test]
+fn off_screen_terrain_is_culled() {
+    with_game(7, |g, ctx| {
+        let mut drawn = |g: &mut LunarLander| {
+            let mut out = Vec::new();
+            g.render(ctx, &mut out);
+            out.into_iter()
+                .filter_map(|c| match c {
+                    DrawCmd::Polyline { pts, .. } => Some(pts),
+                    _ => None,
+                })
+                .flatten()
+                .collect::<Vec<Vec2>>()
+        };
+        let all = drawn(g);
+        // Whatever remains with the ground taken away is the lander and HUD
+        let total = g.terrain.points.len();
+        g.terrain = Terrain { points: vec![], pads: vec![], ..g.terrain.clone() };
+        let ground = all.len() - drawn(g).len();
+        assert!(ground > 0 && ground < total, "only part of the world should be drawn");
+        assert!(all.iter().all(|p| p.x.abs() < 1.7), "everything drawn lies near the view");
+    });
+}
@@ -0,0 +1,132 @@
+use glam::Vec2;
+use lunar_lander::{GameState, LandingPad, LunarLander, Terrain};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+/// Taps Space every step.
+struct TapSpace;
+impl InputState for TapSpace {
+    fn key(&self, k: Key) -> Button {
+        if k == Key::Space { Button { is_down: true, went_down: true, went_up: false } } else { Button::UP }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+/// Flat ground at y = -0.5 with one pad around x = 0.
+fn flat(multiplier: u32) -> Terrain {
+    Terrain {
+        points: vec![Vec2::new(-3.0, -0.5), Vec2::new(3.0, -0.5)],
+        pads: vec![LandingPad { x_min: -0.2, x_max: 0.2, y: -0.5, multiplier }],
+        width: 6.0,
+        seed: None,
+    }
+}
+
+fn playing(ctx: &mut GameCtx) -> LunarLander {
+    let mut g = LunarLander::new();
+    g.reset(ctx);
+    g.state = GameState::Playing;
+    g
+}
+
+/// Drop the lander at `x` with `vel` and step until it comes to rest.
+fn drop_at(g: &mut LunarLander, ctx: &mut GameCtx, x: f32, vel: f32) {
+    g.lander.pos = Vec2::new(x, -0.44);
+    g.lander.vel = Vec2::new(0.0, vel);
+    for _ in 0..100 {
+        g.update(ctx, 0.02);
+        if g.state != GameState::Playing {
+            return;
+        }
+    }
+}
+
+/// Step through the pause after a touchdown.
+fn wait_out_round(g: &mut LunarLander, ctx: &mut GameCtx) {
+    for _ in 0..(LunarLander::ROUND_PAUSE / 0.25) as usize + 2 {
+        g.update(ctx, 0.25);
+    }
+}
+
+#[test]
+fn landing_score_uses_the_pad_multiplier() {
+    let input = NoInput;
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(3);
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = playing(&mut ctx);
+    g.terrain = flat(3);
+    drop_at(&mut g, &mut ctx, 0.0, 0.0);
+    assert!(g.state == GameState::Landed);
+    let expected = (100 + (g.lander.fuel * 10.0) as u32) * 3;
+    assert_eq!(g.last_points, expected);
+    assert_eq!(g.score, expected);
+}
+
+#[test]
+fn fuel_carries_into_a_fresh_round() {
+    let input = NoInput;
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(3);
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = playing(&mut ctx);
+    g.terrain = flat(2);
+    g.lander.fuel = 60.0;
+    drop_at(&mut g, &mut ctx, 0.0, 0.0);
+    assert!(g.state == GameState::Landed);
+    wait_out_round(&mut g, &mut ctx);
+    assert!(g.state == GameState::Playing);
+    assert_eq!(g.round, 2);
+    assert_eq!(g.lander.fuel, 60.0);
+    assert!(g.terrain.points.len() > 2, "new terrain generated for the round");
+    assert!(g.lander.pos.y > 0.5, "back at the top of the sky");
+}
+
+#[test]
+fn crash_costs_fuel() {
+    let input = NoInput;
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(3);
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = playing(&mut ctx);
+    g.terrain = flat(2);
+    drop_at(&mut g, &mut ctx, 0.0, -1.0);
+    assert!(g.state == GameState::Crashed);
+    assert_eq!(g.lander.fuel, 100.0 - LunarLander::CRASH_FUEL);
+    assert_eq!(g.crashes, 1);
+    assert_eq!(g.score, 0);
+}
+
+#[test]
+fn campaign_ends_when_fuel_runs_out_and_space_restarts() {
+    let input = NoInput;
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(3);
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = playing(&mut ctx);
+    g.terrain = flat(2);
+    g.lander.fuel = LunarLander::CRASH_FUEL / 2.0;
+    drop_at(&mut g, &mut ctx, 0.0, -1.0);
+    assert!(g.state == GameState::Crashed);
+    wait_out_round(&mut g, &mut ctx);
+    assert!(g.state == GameState::GameOver);
+
+    let tap = TapSpace;
+    let mut ctx = GameCtx { input: &tap, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    g.update(&mut ctx, 0.02);
+    assert!(g.state == GameState::Playing);
+    assert!(g.lander.fuel > 99.0, "tank refilled for the new campaign");
+    assert_eq!((g.round, g.crashes, g.score), (1, 0, 0));
+}
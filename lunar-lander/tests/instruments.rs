@@ -0,0 +1,81 @@
+use glam::Vec2;
+use lunar_lander::{GameState, LunarLander, Terrain};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::draw::DrawCmd;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+/// Feet of a level lander sit this far below its centre.
+const LEG: f32 = 0.05;
+
+/// Hold the lander at `height` (feet above the flat ground) with `vel` and
+/// `angle`, and collect the HUD text drawn on each of `frames` steps.
+fn hud(height: f32, vel: Vec2, angle: f32, frames: usize) -> Vec<Vec<String>> {
+    let input = NoInput;
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(2);
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = LunarLander::new();
+    g.reset(&mut ctx);
+    g.state = GameState::Playing;
+    g.terrain = Terrain { points: vec![Vec2::new(-3.0, -0.5), Vec2::new(3.0, -0.5)], pads: vec![], width: 6.0, seed: None };
+    (0..frames)
+        .map(|_| {
+            g.lander.pos = Vec2::new(0.0, -0.5 + LEG + height);
+            g.lander.vel = vel;
+            g.lander.angle = angle;
+            g.update(&mut ctx, 0.1);
+            g.lander.pos = Vec2::new(0.0, -0.5 + LEG + height);
+            g.lander.vel = vel;
+            let mut out = Vec::new();
+            g.render(&mut ctx, &mut out);
+            out.into_iter()
+                .filter_map(|c| match c {
+                    DrawCmd::Text { text, .. } => Some(text),
+                    _ => None,
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn shows_radar_altitude_and_split_speeds() {
+    let text = &hud(0.8, Vec2::new(-0.12, -0.07), 0.0, 1)[0];
+    assert!(text.contains(&"ALT: 0.80".to_string()), "{text:?}");
+    assert!(text.contains(&"HSPEED: 0.12".to_string()), "{text:?}");
+    assert!(text.contains(&"VSPEED: 0.07".to_string()), "{text:?}");
+}
+
+#[test]
+fn warns_when_too_fast_near_the_ground() {
+    let frames = hud(0.2, Vec2::new(0.0, -0.4), 0.0, 10);
+    let warned = frames.iter().filter(|t| t.contains(&"TOO FAST".to_string())).count();
+    assert!(warned > 0, "warning shown");
+    assert!(warned < frames.len(), "warning flashes");
+}
+
+#[test]
+fn warns_when_too_steep_near_the_ground() {
+    let frames = hud(0.2, Vec2::ZERO, 0.5, 10);
+    assert!(frames.iter().any(|t| t.contains(&"TOO STEEP".to_string())));
+}
+
+#[test]
+fn quiet_when_high_or_safe() {
+    let high = hud(0.8, Vec2::new(0.0, -0.4), 0.5, 10);
+    let safe = hud(0.2, Vec2::new(0.0, -0.1), 0.1, 10);
+    for text in high.iter().chain(&safe) {
+        assert!(!text.iter().any(|t| t.starts_with("TOO ")), "{text:?}");
+    }
+}
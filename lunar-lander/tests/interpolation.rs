@@ -0,0 +1,82 @@
+use glam::Vec2;
+use lunar_lander::{GameState, Lander, LunarLander, Terrain};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::draw::DrawCmd;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+/// Top of the lander body as drawn: the first closed outline.
+fn drawn_nose(out: &[DrawCmd]) -> Vec2 {
+    out.iter()
+        .find_map(|c| match c {
+            DrawCmd::Polyline { pts, closed: true, .. } => Some(pts[0]),
+            _ => None,
+        })
+        .expect("lander body")
+}
+
+#[test]
+fn lander_moves_smoothly_between_steps() {
+    let mut rng = Xorshift64::new(3);
+    let mut ctx = GameCtx { input: &NoInput, audio: &NoAudio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = LunarLander::new();
+    g.reset(&mut ctx);
+    g.state = GameState::Playing;
+    // 144 Hz display over a 120 Hz simulation: some frames run no step at
+    // all, yet the lander should fall a little on every one of them
+    let mut last = f32::MAX;
+    for frame in 0..30 {
+        g.update(&mut ctx, 1.0 / 144.0);
+        let mut out = Vec::new();
+        g.render(&mut ctx, &mut out);
+        let nose = drawn_nose(&out);
+        assert!(nose.y < last, "frame {frame}: lander drawn at {nose:?}, was at y {last}");
+        assert!(nose.x.abs() < 1e-5, "frame {frame}: camera should stay on the lander, nose at {nose:?}");
+        last = nose.y;
+    }
+}
+
+#[test]
+fn landed_lander_holds_still() {
+    let mut rng = Xorshift64::new(3);
+    let mut ctx = GameCtx { input: &NoInput, audio: &NoAudio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = LunarLander::new();
+    g.reset(&mut ctx);
+    g.state = GameState::Playing;
+    g.update(&mut ctx, 1.0 / 60.0);
+    // Touch down mid-frame, as the last flying step would
+    g.state = GameState::Landed;
+    g.round_timer = 1000.0;
+    g.update(&mut ctx, 1.0 / 60.0);
+    let nose = |g: &mut LunarLander, ctx: &mut GameCtx| {
+        let mut out = Vec::new();
+        g.render(ctx, &mut out);
+        drawn_nose(&out)
+    };
+    let still = nose(&mut g, &mut ctx);
+    for frame in 0..10 {
+        g.update(&mut ctx, 1.0 / 144.0);
+        assert_eq!(nose(&mut g, &mut ctx), still, "frame {frame}: lander moved after landing");
+    }
+}
+
+#[test]
+fn blending_takes_the_short_way_across_the_seam() {
+    let terrain = Terrain::from_seed(1, 0.0);
+    let edge = terrain.width / 2.0;
+    let mut lander = Lander::new();
+    lander.prev_pos = Vec2::new(edge - 0.01, 0.5);
+    lander.pos = Vec2::new(-edge + 0.01, 0.5);
+    let halfway = lander.interpolated(0.5, &terrain);
+    assert!(halfway.pos.x.abs() > edge - 0.01, "blended across the map to {:?}", halfway.pos);
+}
@@ -1,4 +1,6 @@
 use lunar_lander::{LunarLander, GameState};
+use arcade_kit::sound::{SoundEvent, SoundLog};
+use glam::Vec2;
 use vectorcade_shared::Xorshift64;
 use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
 use vectorcade_shared::input::{Axis, Button, InputState, Key};
@@ -33,3 +35,40 @@ fn lunar_lander_advances() {
     g.update(&mut ctx, 0.016);
     assert!(g.lander.pos.y < y0, "gravity should pull lander down");
 }
+
+/// Holds the thrust key.
+struct Thrust;
+impl InputState for Thrust {
+    fn key(&self, k: Key) -> Button {
+        if k == Key::Up { Button { is_down: true, went_down: false, went_up: false } } else { Button::UP }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+#[test]
+fn lunar_lander_emits_sounds() {
+    let input = Thrust;
+    let audio = SoundLog::new();
+    let mut rng = Xorshift64::new(12345);
+    let mut ctx = GameCtx {
+        input: &input,
+        audio: &audio,
+        rng: &mut rng,
+        screen: ScreenInfo::default(),
+        now_s: 0.0,
+    };
+    let mut g = LunarLander::new();
+    g.reset(&mut ctx);
+    g.state = GameState::Playing;
+    g.update(&mut ctx, 0.016);
+    assert!(audio.contains(SoundEvent::ThrustStart));
+    // Slam into the ground
+    let x = g.lander.pos.x;
+    g.lander.pos = Vec2::new(x, g.terrain.surface_y_at(x) + 0.06);
+    g.lander.vel = Vec2::new(0.0, -2.0);
+    g.update(&mut ctx, 0.05);
+    assert!(g.state == GameState::Crashed);
+    assert!(audio.contains(SoundEvent::LanderCrash));
+    assert!(audio.contains(SoundEvent::ThrustStop), "engine loop stops on impact");
+}
@@ -0,0 +1,120 @@
+use glam::Vec2;
+use lunar_lander::{Debris, Dust, GameState, Lander, LunarLander, Terrain, tumble};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+/// Holds Space for a full burn when `thrust` is set.
+struct Pilot {
+    thrust: bool,
+}
+
+impl InputState for Pilot {
+    fn key(&self, k: Key) -> Button {
+        if self.thrust && k == Key::Space { Button { is_down: true, went_down: false, went_up: false } } else { Button::UP }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+const GROUND: f32 = -0.5;
+
+fn flat() -> Terrain {
+    Terrain { points: vec![Vec2::new(-3.0, GROUND), Vec2::new(3.0, GROUND)], pads: vec![], width: 6.0, seed: None }
+}
+
+#[test]
+fn crash_breaks_the_lander_into_its_outline() {
+    let input = Pilot { thrust: false };
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(4);
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = LunarLander::new();
+    g.reset(&mut ctx);
+    g.state = GameState::Playing;
+    g.terrain = flat();
+    g.lander.pos = Vec2::new(0.0, GROUND + 0.1);
+    g.lander.vel = Vec2::new(0.0, -1.5);
+    g.update(&mut ctx, 0.1);
+    assert!(g.state == GameState::Crashed);
+    // Three body edges and two legs
+    assert_eq!(g.debris.len(), 5);
+    let leg = Lander::LEGS[0][1] - Lander::LEGS[0][0];
+    assert!(g.debris.iter().any(|d| (d.half_len * 2.0 - leg.length()).abs() < 1e-5));
+
+    // The pieces scatter, then come to rest on the ground
+    for _ in 0..12 {
+        g.update(&mut ctx, 0.25);
+    }
+    for piece in &g.debris {
+        let (a, b) = piece.endpoints();
+        assert!(a.y >= GROUND - 1e-3 && b.y >= GROUND - 1e-3, "piece sank into the ground");
+    }
+}
+
+#[test]
+fn debris_bounces_off_slopes() {
+    let terrain = flat();
+    let mut piece = Debris::from_segment(Vec2::new(-0.01, GROUND + 0.05), Vec2::new(0.01, GROUND + 0.05), Vec2::new(0.1, -0.5), 0.0);
+    let mut bounced = false;
+    for _ in 0..60 {
+        tumble(&mut piece, &terrain, 1.0 / 120.0);
+        bounced |= piece.vel.y > 0.0;
+    }
+    assert!(bounced, "piece should rebound");
+    assert!(piece.vel.y.abs() < 0.5, "and lose energy doing so");
+
+    // Falling onto a 45 degree slope rising to the right kicks it left
+    let slope = Terrain {
+        points: vec![Vec2::new(-3.0, -3.5), Vec2::new(3.0, 2.5)],
+        pads: vec![],
+        width: 6.0,
+        seed: None,
+    };
+    let mut piece = Debris::from_segment(Vec2::new(-0.01, -0.47), Vec2::new(0.01, -0.47), Vec2::new(0.0, -0.5), 0.0);
+    for _ in 0..10 {
+        tumble(&mut piece, &slope, 1.0 / 120.0);
+    }
+    assert!(piece.vel.x < 0.0, "vel {:?}", piece.vel);
+}
+
+#[test]
+fn dust_wraps_across_the_seam() {
+    let terrain = flat();
+    let mut puff = Dust { pos: Vec2::new(2.999, GROUND + 0.05), vel: Vec2::new(0.4, 0.0), lifetime: Dust::MAX_LIFETIME };
+    for _ in 0..6 {
+        puff.update(&terrain, 1.0 / 120.0);
+    }
+    assert!(puff.is_alive());
+    assert!(puff.pos.x > -3.0 && puff.pos.x < -2.98, "puff should come round the far side, at {:?}", puff.pos);
+}
+
+#[test]
+fn exhaust_raises_dust_only_near_the_ground() {
+    let dust_after = |height: f32| {
+        let input = Pilot { thrust: true };
+        let audio = NoAudio;
+        let mut rng = Xorshift64::new(4);
+        let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+        let mut g = LunarLander::new();
+        g.reset(&mut ctx);
+        g.state = GameState::Playing;
+        g.terrain = flat();
+        let mut seen = 0;
+        for _ in 0..20 {
+            g.lander.pos = Vec2::new(0.0, GROUND + height);
+            g.lander.vel = Vec2::ZERO;
+            g.update(&mut ctx, 0.05);
+            seen = seen.max(g.dust.len());
+        }
+        (seen, g)
+    };
+    let (low, g) = dust_after(0.12);
+    assert!(low > 0, "hovering low should stir up dust");
+    assert!(g.dust.iter().any(|d| d.vel.x < 0.0) && g.dust.iter().any(|d| d.vel.x > 0.0), "dust blows both ways");
+    let (high, _) = dust_after(0.6);
+    assert_eq!(high, 0);
+}
@@ -0,0 +1,142 @@
+use lunar_lander::{GameState, LunarLander, Terrain, TerrainError};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::draw::DrawCmd;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+/// Widest the lander stands, foot to foot.
+const FOOTPRINT: f32 = 0.07;
+
+#[test]
+fn every_layout_has_two_to_four_usable_pads() {
+    for seed in 1..200 {
+        for difficulty in [0.0, 0.5, 1.0] {
+            let t = Terrain::from_seed(seed, difficulty);
+            assert!((2..=4).contains(&t.pads.len()), "seed {seed}: {} pads", t.pads.len());
+            for pair in t.pads.windows(2) {
+                assert!(pair[0].x_max < pair[1].x_min, "seed {seed}: pads overlap");
+            }
+            for pad in &t.pads {
+                assert!(pad.x_max - pad.x_min > FOOTPRINT, "seed {seed}: pad too narrow for the lander");
+                for x in [pad.x_min, (pad.x_min + pad.x_max) / 2.0, pad.x_max] {
+                    assert!((t.surface_y_at(x) - pad.y).abs() < 1e-5, "seed {seed}: pad not flat");
+                }
+            }
+            assert!(t.points.windows(2).all(|w| w[0].x < w[1].x), "seed {seed}: points out of order");
+            assert_eq!(t.points[0].y, t.points.last().unwrap().y, "seed {seed}: seam doesn't meet");
+        }
+    }
+}
+
+#[test]
+fn hard_pads_sit_on_peaks_and_in_valleys() {
+    let (mut peaks, mut valleys, mut ground) = (Vec::new(), Vec::new(), Vec::new());
+    for seed in 1..200 {
+        let t = Terrain::from_seed(seed, 1.0);
+        ground.extend(t.points.iter().map(|p| p.y));
+        for pad in &t.pads {
+            match pad.multiplier {
+                5 => peaks.push(pad.y),
+                3 => valleys.push(pad.y),
+                _ => {}
+            }
+        }
+    }
+    let mean = |v: &[f32]| v.iter().sum::<f32>() / v.len() as f32;
+    assert!(!peaks.is_empty() && !valleys.is_empty());
+    assert!(mean(&peaks) > mean(&ground) + 0.1, "peak pads should be high");
+    assert!(mean(&valleys) < mean(&ground) - 0.1, "valley pads should be low");
+}
+
+#[test]
+fn difficulty_narrows_the_pads() {
+    let stats = |difficulty: f32| {
+        let pads: Vec<_> = (1..200).flat_map(|seed| Terrain::from_seed(seed, difficulty).pads).collect();
+        let hard = pads.iter().filter(|p| p.multiplier >= 3).count() as f32 / pads.len() as f32;
+        let width = pads.iter().map(|p| p.x_max - p.x_min).sum::<f32>() / pads.len() as f32;
+        (hard, width)
+    };
+    let (easy, hard) = (stats(0.0), stats(1.0));
+    assert!(hard.0 > easy.0 + 0.2, "more hard pads: {} vs {}", hard.0, easy.0);
+    assert!(hard.1 < easy.1, "narrower pads: {} vs {}", hard.1, easy.1);
+}
+
+#[test]
+fn seeds_reproduce_layouts() {
+    let a = Terrain::from_seed(42, 0.5);
+    assert_eq!(a, Terrain::from_seed(42, 0.5));
+    assert_ne!(a, Terrain::from_seed(43, 0.5));
+    assert_eq!(a.seed, Some(42));
+}
+
+#[test]
+fn game_prints_the_seed_of_each_round() {
+    let input = NoInput;
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(9);
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = LunarLander::new();
+    g.reset(&mut ctx);
+    g.state = GameState::Playing;
+    let seed = g.terrain.seed.expect("campaign terrain is seeded");
+    assert_eq!(g.terrain, Terrain::from_seed(seed, 0.0));
+    let mut out = Vec::new();
+    g.render(&mut ctx, &mut out);
+    let label = format!("SEED {seed}");
+    assert!(out.iter().any(|c| matches!(c, DrawCmd::Text { text, .. } if *text == label)));
+}
+
+#[test]
+fn parses_hand_authored_terrain() {
+    let text = "
+        # A single valley pad
+        point -2.0 -0.4
+        point -0.3 -0.8   # valley floor
+        point  0.3 -0.8
+        point  2.0 -0.4
+        pad -0.2 0.2 3
+    ";
+    let t = Terrain::parse(text).unwrap();
+    assert_eq!(t.width, 4.0);
+    assert_eq!(t.points.len(), 4);
+    assert_eq!(t.pads.len(), 1);
+    let pad = &t.pads[0];
+    assert_eq!((pad.x_min, pad.x_max, pad.y, pad.multiplier), (-0.2, 0.2, -0.8, 3));
+    assert_eq!(t.seed, None);
+}
+
+#[test]
+fn rejects_malformed_terrain() {
+    let err = |text: &str| Terrain::parse(text).unwrap_err();
+    assert_eq!(err("point -1 0\nhill 0 0\npoint 1 0"), TerrainError::BadLine(2));
+    assert_eq!(err("point -1 0\npoint one 0"), TerrainError::BadLine(2));
+    assert_eq!(err("point -1 0"), TerrainError::TooFewPoints);
+    assert_eq!(err("point -1 0\npoint 0.5 0\npoint 0.2 0\npoint 1 0"), TerrainError::Unordered(3));
+    assert_eq!(err("point -1 0\npoint 2 0"), TerrainError::NotCentred);
+    assert_eq!(err("point -1 0\npoint 0 -0.5\npoint 1 0\npad -0.5 0.5 2"), TerrainError::PadNotFlat(4));
+    assert_eq!(err("point -1 0\npoint 1 0\npad -0.5 0.5 0"), TerrainError::BadLine(3));
+}
+
+#[test]
+fn rejects_a_cliff_at_the_seam() {
+    let err = Terrain::parse("point -1 -0.4\npoint 0 -0.5\npoint 1 -0.8").unwrap_err();
+    assert_eq!(err, TerrainError::SeamMismatch);
+}
+
+#[test]
+fn rejects_overlapping_pads() {
+    let text = "point -1 0\npoint 1 0\npad -0.5 0.1 1\npad 0.0 0.4 2";
+    assert_eq!(Terrain::parse(text).unwrap_err(), TerrainError::PadsOverlap(4));
+    // Pads side by side are fine
+    assert_eq!(Terrain::parse("point -1 0\npoint 1 0\npad -0.5 0.0 1\npad 0.0 0.4 2").unwrap().pads.len(), 2);
+}
@@ -0,0 +1,119 @@
+use glam::Vec2;
+use lunar_lander::{GameState, LunarLander};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::draw::DrawCmd;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+/// Holds the given keys and reports fixed stick positions.
+struct Pilot {
+    keys: &'static [Key],
+    x: f32,
+    y: f32,
+}
+
+impl InputState for Pilot {
+    fn key(&self, k: Key) -> Button {
+        if self.keys.contains(&k) { Button { is_down: true, went_down: false, went_up: false } } else { Button::UP }
+    }
+    fn axis(&self, a: Axis) -> f32 {
+        match a {
+            Axis::LeftX => self.x,
+            Axis::LeftY => self.y,
+            _ => 0.0,
+        }
+    }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+/// Fly for `seconds` high above the terrain with `pilot` at the controls.
+fn fly(g: &mut LunarLander, pilot: &Pilot, seconds: f32) {
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(5);
+    let mut ctx = GameCtx { input: pilot, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    if g.state == GameState::Instructions {
+        g.reset(&mut ctx);
+        g.state = GameState::Playing;
+    }
+    for _ in 0..(seconds / 0.25).round() as usize {
+        g.lander.pos = Vec2::new(0.0, 5.0);
+        g.update(&mut ctx, 0.25);
+    }
+}
+
+const IDLE: Pilot = Pilot { keys: &[], x: 0.0, y: 0.0 };
+
+#[test]
+fn up_and_down_move_the_lever_gradually() {
+    let mut g = LunarLander::new();
+    fly(&mut g, &Pilot { keys: &[Key::Up], ..IDLE }, 0.25);
+    let partial = g.lander.throttle;
+    assert!(partial > 0.0 && partial < 1.0, "throttle {partial}");
+    fly(&mut g, &Pilot { keys: &[Key::Up], ..IDLE }, 1.0);
+    assert_eq!(g.lander.throttle, 1.0);
+    fly(&mut g, &IDLE, 0.5);
+    assert_eq!(g.lander.throttle, 1.0, "lever stays where it was left");
+    fly(&mut g, &Pilot { keys: &[Key::Down], ..IDLE }, 1.0);
+    assert_eq!(g.lander.throttle, 0.0);
+    assert!(!g.lander.thrusting);
+}
+
+#[test]
+fn fuel_burn_scales_with_throttle() {
+    let burn = |lever: f32| {
+        let mut g = LunarLander::new();
+        fly(&mut g, &IDLE, 0.0);
+        g.lander.lever = lever;
+        fly(&mut g, &IDLE, 2.0);
+        100.0 - g.lander.fuel
+    };
+    let (full, half) = (burn(1.0), burn(0.5));
+    assert!(full > 0.0);
+    assert!((half - full / 2.0).abs() < 1e-3, "half {half} vs full {full}");
+}
+
+#[test]
+fn space_gives_a_full_burn_over_the_lever() {
+    let mut g = LunarLander::new();
+    fly(&mut g, &IDLE, 0.0);
+    g.lander.lever = 0.2;
+    fly(&mut g, &Pilot { keys: &[Key::Space], ..IDLE }, 0.25);
+    assert_eq!(g.lander.throttle, 1.0);
+    fly(&mut g, &IDLE, 0.25);
+    assert!((g.lander.throttle - 0.2).abs() < 1e-6);
+}
+
+#[test]
+fn sticks_set_throttle_and_rotation_rate() {
+    let mut g = LunarLander::new();
+    fly(&mut g, &Pilot { y: 0.6, ..IDLE }, 0.25);
+    assert!((g.lander.throttle - 0.6).abs() < 1e-6);
+
+    let turn = |x: f32| {
+        let mut g = LunarLander::new();
+        fly(&mut g, &Pilot { x, ..IDLE }, 0.25);
+        g.lander.angle
+    };
+    let (full, half) = (turn(1.0), turn(0.5));
+    assert!(full > 0.0, "stick right turns like the Right key");
+    assert!((half - full / 2.0).abs() < 1e-4);
+    // Inside the deadzone nothing happens
+    assert_eq!(turn(0.05), 0.0);
+}
+
+#[test]
+fn hud_shows_a_throttle_gauge() {
+    let mut g = LunarLander::new();
+    fly(&mut g, &IDLE, 0.0);
+    g.lander.lever = 0.5;
+    fly(&mut g, &IDLE, 0.25);
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(5);
+    let mut ctx = GameCtx { input: &IDLE, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut out = Vec::new();
+    g.render(&mut ctx, &mut out);
+    assert!(out.iter().any(|c| matches!(c, DrawCmd::Text { text, .. } if text == "THR:  50%")));
+}
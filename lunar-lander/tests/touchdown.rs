@@ -0,0 +1,101 @@
+use glam::Vec2;
+use lunar_lander::{GameState, Lander, LandingPad, LunarLander, Terrain};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+const GROUND: f32 = -0.5;
+
+fn pad(x_min: f32, x_max: f32) -> LandingPad {
+    LandingPad { x_min, x_max, y: GROUND, multiplier: 2 }
+}
+
+/// Drop a lander onto `terrain` from just above it and report how it ends up.
+fn touch_down(terrain: Terrain, x: f32, angle: f32, height: f32) -> (GameState, Lander) {
+    let input = NoInput;
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(1);
+    let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = LunarLander::new();
+    g.reset(&mut ctx);
+    g.state = GameState::Playing;
+    g.terrain = terrain;
+    g.lander.angle = angle;
+    g.lander.vel = Vec2::ZERO;
+    g.lander.pos = Vec2::new(x, height);
+    for _ in 0..200 {
+        g.update(&mut ctx, 0.02);
+        if g.state != GameState::Playing {
+            break;
+        }
+    }
+    (g.state, g.lander)
+}
+
+fn flat(pads: Vec<LandingPad>) -> Terrain {
+    Terrain { points: vec![Vec2::new(-3.0, GROUND), Vec2::new(3.0, GROUND)], pads, width: 6.0, seed: None }
+}
+
+#[test]
+fn level_touchdown_on_a_pad_lands() {
+    let (state, _) = touch_down(flat(vec![pad(-0.2, 0.2)]), 0.0, 0.0, GROUND + 0.06);
+    assert!(state == GameState::Landed);
+}
+
+#[test]
+fn one_foot_first_settles_onto_both() {
+    // Left foot low: the lander swings level onto its right foot
+    let (state, lander) = touch_down(flat(vec![pad(-0.3, 0.3)]), 0.0, 0.2, GROUND + 0.07);
+    assert!(state == GameState::Landed);
+    assert!(lander.angle.abs() < 0.1, "settled level, angle {}", lander.angle);
+}
+
+#[test]
+fn overhanging_foot_tips_the_lander_over() {
+    // Pad ends just past the left foot; the ground falls away beneath the right
+    let terrain = Terrain {
+        points: vec![Vec2::new(-3.0, GROUND), Vec2::new(0.17, GROUND), Vec2::new(0.3, -0.9), Vec2::new(3.0, -0.9)],
+        pads: vec![pad(-0.2, 0.17)],
+        width: 6.0,
+        seed: None,
+    };
+    let (state, lander) = touch_down(terrain, 0.19, 0.0, GROUND + 0.055);
+    assert!(state == GameState::Crashed);
+    assert!(lander.angle < 0.0, "toppled to the right");
+}
+
+#[test]
+fn feet_on_different_pads_crash() {
+    let (state, _) = touch_down(flat(vec![pad(-0.2, 0.0), pad(0.0, 0.2)]), 0.0, 0.0, GROUND + 0.06);
+    assert!(state == GameState::Crashed);
+}
+
+#[test]
+fn peak_between_the_legs_hits_the_body() {
+    // A spike rises under the body while both feet are still in the air
+    let terrain = Terrain {
+        points: vec![
+            Vec2::new(-3.0, GROUND),
+            Vec2::new(-0.01, GROUND),
+            Vec2::new(0.0, GROUND + 0.045),
+            Vec2::new(0.01, GROUND),
+            Vec2::new(3.0, GROUND),
+        ],
+        pads: vec![],
+        width: 6.0,
+        seed: None,
+    };
+    let (state, lander) = touch_down(terrain, 0.0, 0.0, GROUND + 0.07);
+    assert!(state == GameState::Crashed);
+    assert!(lander.feet().iter().all(|f| f.y > GROUND), "crashed on the body, not the feet");
+}
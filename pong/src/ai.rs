@@ -0,0 +1,117 @@
+//! CPU opponent for single-player Pong.
+
+use glam::Vec2;
+use vectorcade_shared::GameRng;
+
+use crate::physics::{PADDLE_X_R, WALL_Y};
+
+/// CPU skill presets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// Seconds between the ball turning toward the CPU and the CPU reacting.
+    pub fn reaction_delay(self) -> f32 {
+        match self {
+            Self::Easy => 0.45,
+            Self::Normal => 0.25,
+            Self::Hard => 0.1,
+        }
+    }
+
+    /// Paddle speed cap, in units per second.
+    pub fn max_speed(self) -> f32 {
+        match self {
+            Self::Easy => 0.6,
+            Self::Normal => 0.9,
+            Self::Hard => 1.3,
+        }
+    }
+
+    /// Largest random offset added to the predicted intercept.
+    pub fn aim_error(self) -> f32 {
+        match self {
+            Self::Easy => 0.22,
+            Self::Normal => 0.12,
+            Self::Hard => 0.03,
+        }
+    }
+
+    pub fn harder(self) -> Self {
+        match self {
+            Self::Easy => Self::Normal,
+            _ => Self::Hard,
+        }
+    }
+
+    pub fn easier(self) -> Self {
+        match self {
+            Self::Hard => Self::Normal,
+            _ => Self::Easy,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Easy => "EASY",
+            Self::Normal => "NORMAL",
+            Self::Hard => "HARD",
+        }
+    }
+}
+
+/// Where a ball at `ball` moving with `vel` will cross the vertical line `x`,
+/// folding the path off walls at `±wall`. `None` if it is moving away.
+pub fn predict_intercept(ball: Vec2, vel: Vec2, x: f32, wall: f32) -> Option<f32> {
+    if vel.x == 0.0 || (x - ball.x) * vel.x < 0.0 { return None; }
+    let t = (x - ball.x) / vel.x;
+    let span = 2.0 * wall;
+    let mut y = (ball.y + vel.y * t + wall).rem_euclid(2.0 * span);
+    if y > span { y = 2.0 * span - y; }
+    Some(y - wall)
+}
+
+/// State for the CPU-driven paddle.
+pub struct CpuPaddle {
+    pub difficulty: Difficulty,
+    /// Paddle centre the CPU is heading for.
+    pub target: f32,
+    pub reaction_timer: f32,
+    aim_offset: f32,
+    tracking: bool,
+}
+
+impl CpuPaddle {
+    pub fn new(difficulty: Difficulty) -> Self {
+        Self { difficulty, target: 0.0, reaction_timer: 0.0, aim_offset: 0.0, tracking: false }
+    }
+
+    /// Steer the right paddle toward the ball's predicted intercept.
+    pub fn update(&mut self, paddle: &mut f32, ball: Vec2, vel: Vec2, rng: &mut dyn GameRng, dt: f32) {
+        let approaching = vel.x > 0.0 && ball.x < PADDLE_X_R;
+        if approaching && !self.tracking {
+            // New rally toward us: hesitate, then commit to a slightly wrong guess
+            self.reaction_timer = self.difficulty.reaction_delay();
+            let err = self.difficulty.aim_error();
+            self.aim_offset = rng.range_f32(-err, err);
+        }
+        self.tracking = approaching;
+        if !approaching {
+            self.target = 0.0;
+        } else if self.reaction_timer > 0.0 {
+            self.reaction_timer -= dt;
+        } else if let Some(y) = predict_intercept(ball, vel, PADDLE_X_R, WALL_Y) {
+            self.target = y + self.aim_offset;
+        }
+        let max_step = self.difficulty.max_speed() * dt;
+        *paddle += (self.target - *paddle).clamp(-max_step, max_step);
+    }
+}
+
+impl Default for CpuPaddle {
+    fn default() -> Self { Self::new(Difficulty::Normal) }
+}
@@ -5,6 +5,8 @@ use vectorcade_shared::{
     font::FontStyleId,
 };
 
+use crate::{Difficulty, Mode, Side};
+
 const PADDLE_HALF: f32 = 0.18;
 
 pub fn render_court(out: &mut Vec<DrawCmd>, paddle_l: f32, paddle_r: f32, ball: Vec2) {
@@ -58,17 +60,33 @@ pub fn render_scores(out: &mut Vec<DrawCmd>, score_l: u32, score_r: u32, style:
     });
 }
 
-pub fn render_instructions(out: &mut Vec<DrawCmd>, style: FontStyleId, blink_timer: f32) {
+pub fn render_instructions(
+    out: &mut Vec<DrawCmd>,
+    style: FontStyleId,
+    blink_timer: f32,
+    mode: Mode,
+    difficulty: Difficulty,
+) {
     let white = Rgba::WHITE;
-    let lines = [
-        ("PONG", -0.12, 0.6, 96.0),
-        ("2 PLAYER GAME", -0.50, 0.35, 56.0),
-        ("LEFT PADDLE: W/S", -0.50, 0.1, 48.0),
-        ("RIGHT PADDLE: UP/DOWN", -0.65, -0.15, 48.0),
+    let mark = |m: Mode| if m == mode { ">" } else { " " };
+    let mut lines = vec![
+        ("PONG".to_string(), -0.12, 0.6, 96.0),
+        (format!("{} 1 PLAYER", mark(Mode::OnePlayer)), -0.50, 0.38, 48.0),
+        (format!("{} 2 PLAYERS", mark(Mode::TwoPlayer)), -0.50, 0.24, 48.0),
     ];
+    match mode {
+        Mode::OnePlayer => {
+            lines.push((format!("DIFFICULTY: {}", difficulty.label()), -0.50, 0.02, 48.0));
+            lines.push(("MOVE: W/S OR UP/DOWN".to_string(), -0.60, -0.2, 48.0));
+        }
+        Mode::TwoPlayer => {
+            lines.push(("LEFT PADDLE: W/S".to_string(), -0.50, 0.02, 48.0));
+            lines.push(("RIGHT PADDLE: UP/DOWN".to_string(), -0.65, -0.2, 48.0));
+        }
+    }
     for (text, x, y, size) in lines {
         out.push(DrawCmd::Text {
-            pos: Vec2::new(x, y), text: text.to_string(), size_px: size, color: white, style,
+            pos: Vec2::new(x, y), text, size_px: size, color: white, style,
         });
     }
     // Blinking "PRESS SPACE TO START"
@@ -78,3 +96,24 @@ pub fn render_instructions(out: &mut Vec<DrawCmd>, style: FontStyleId, blink_tim
         });
     }
 }
+
+pub fn render_game_over(out: &mut Vec<DrawCmd>, style: FontStyleId, blink_timer: f32, winner: Side, mode: Mode) {
+    let white = Rgba::WHITE;
+    let (text, x) = match (mode, winner) {
+        (Mode::OnePlayer, Side::Left) => ("YOU WIN", -0.28),
+        (Mode::OnePlayer, Side::Right) => ("CPU WINS", -0.32),
+        (Mode::TwoPlayer, Side::Left) => ("LEFT PLAYER WINS", -0.62),
+        (Mode::TwoPlayer, Side::Right) => ("RIGHT PLAYER WINS", -0.66),
+    };
+    out.push(DrawCmd::Text {
+        pos: Vec2::new(-0.38, 0.3), text: "GAME OVER".to_string(), size_px: 80.0, color: white, style,
+    });
+    out.push(DrawCmd::Text {
+        pos: Vec2::new(x, 0.05), text: text.to_string(), size_px: 56.0, color: white, style,
+    });
+    if (blink_timer % 1.0) < 0.6 {
+        out.push(DrawCmd::Text {
+            pos: Vec2::new(-0.74, -0.5), text: "PRESS SPACE FOR REMATCH".to_string(), size_px: 48.0, color: white, style,
+        });
+    }
+}
@@ -1,6 +1,11 @@
+mod ai;
 mod drawing;
 mod physics;
 
+pub use ai::{CpuPaddle, Difficulty, predict_intercept};
+pub use physics::{SweepHit, match_winner, sweep_box};
+
+use arcade_kit::timestep::{FixedStep, FixedUpdate, run_fixed};
 use glam::Vec2;
 use vectorcade_shared::{
     Rgba,
@@ -10,16 +15,52 @@ use vectorcade_shared::{
     input::Key,
 };
 
+/// Who controls the right paddle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    OnePlayer,
+    TwoPlayer,
+}
+
+/// A side of the court.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    pub fn other(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
 pub struct Pong {
     pub ball: Vec2,
     pub vel: Vec2,
     pub paddle_l: f32,
     pub paddle_r: f32,
+    /// Ball and paddles before the latest step; `render` blends from these
+    /// by the clock's `alpha` so motion stays smooth at any display rate.
+    pub prev_ball: Vec2,
+    pub prev_paddle_l: f32,
+    pub prev_paddle_r: f32,
     pub score_l: u32,
     pub score_r: u32,
+    /// Side the next serve travels toward.
+    pub serve_to: Side,
+    /// Pause before the next serve; the ball waits at centre while positive.
+    pub serve_timer: f32,
+    pub winner: Option<Side>,
+    pub mode: Mode,
+    pub cpu: CpuPaddle,
     pub font_style: FontStyleId,
     pub showing_instructions: bool,
     pub blink_timer: f32,
+    pub clock: FixedStep,
 }
 
 impl Default for Pong {
@@ -32,16 +73,41 @@ impl Pong {
     pub fn new() -> Self {
         Self {
             ball: Vec2::ZERO,
-            vel: Vec2::new(0.6, 0.25),
+            vel: Vec2::ZERO,
             paddle_l: 0.0,
             paddle_r: 0.0,
+            prev_ball: Vec2::ZERO,
+            prev_paddle_l: 0.0,
+            prev_paddle_r: 0.0,
             score_l: 0,
             score_r: 0,
+            serve_to: Side::Right,
+            serve_timer: physics::SERVE_DELAY,
+            winner: None,
+            mode: Mode::TwoPlayer,
+            cpu: CpuPaddle::default(),
             font_style: FontStyleId::ATARI,
             showing_instructions: true,
             blink_timer: 0.0,
+            clock: FixedStep::default(),
         }
     }
+
+    /// Zero the scores and wait at centre for the opening serve.
+    pub fn start_match(&mut self) {
+        self.ball = Vec2::ZERO;
+        self.vel = Vec2::ZERO;
+        self.paddle_l = 0.0;
+        self.paddle_r = 0.0;
+        self.prev_ball = self.ball;
+        self.prev_paddle_l = 0.0;
+        self.prev_paddle_r = 0.0;
+        self.score_l = 0;
+        self.score_r = 0;
+        self.serve_to = Side::Right;
+        self.serve_timer = physics::SERVE_DELAY;
+        self.winner = None;
+    }
 }
 
 impl Game for Pong {
@@ -53,35 +119,74 @@ impl Game for Pong {
     }
 
     fn reset(&mut self, _ctx: &mut GameCtx) {
-        self.ball = Vec2::ZERO;
-        self.vel = Vec2::new(0.6, 0.25);
-        self.paddle_l = 0.0;
-        self.paddle_r = 0.0;
-        self.score_l = 0;
-        self.score_r = 0;
+        self.start_match();
+        // Mode and difficulty are player choices and survive a reset
+        self.cpu = CpuPaddle::new(self.cpu.difficulty);
         self.showing_instructions = true;
         self.blink_timer = 0.0;
+        self.clock.reset();
     }
 
     fn update(&mut self, ctx: &mut GameCtx, dt: f32) {
-        if self.showing_instructions {
-            self.blink_timer += dt;
-            if ctx.input.key(Key::Space).went_down {
-                self.showing_instructions = false;
-            }
-            return;
-        }
-        physics::update_paddles(self, ctx, dt);
-        physics::update_ball(self, dt);
+        run_fixed(self, ctx, dt);
     }
 
     fn render(&mut self, _ctx: &mut GameCtx, out: &mut Vec<DrawCmd>) {
         out.push(DrawCmd::Clear { color: Rgba::BLACK });
         if self.showing_instructions {
-            drawing::render_instructions(out, self.font_style, self.blink_timer);
+            drawing::render_instructions(out, self.font_style, self.blink_timer, self.mode, self.cpu.difficulty);
+        } else if let Some(winner) = self.winner {
+            drawing::render_scores(out, self.score_l, self.score_r, self.font_style);
+            drawing::render_game_over(out, self.font_style, self.blink_timer, winner, self.mode);
         } else {
-            drawing::render_court(out, self.paddle_l, self.paddle_r, self.ball);
+            let alpha = self.clock.alpha();
+            let lerp = |from: f32, to: f32| from + (to - from) * alpha;
+            drawing::render_court(
+                out,
+                lerp(self.prev_paddle_l, self.paddle_l),
+                lerp(self.prev_paddle_r, self.paddle_r),
+                self.prev_ball.lerp(self.ball, alpha),
+            );
             drawing::render_scores(out, self.score_l, self.score_r, self.font_style);
         }
     }
 }
+
+impl FixedUpdate for Pong {
+    fn clock(&mut self) -> &mut FixedStep { &mut self.clock }
+
+    fn fixed_update(&mut self, ctx: &mut GameCtx, dt: f32) {
+        if self.showing_instructions {
+            self.blink_timer += dt;
+            let key = |k| ctx.input.key(k).went_down;
+            if key(Key::Up) || key(Key::Down) || key(Key::W) || key(Key::S) {
+                self.mode = match self.mode {
+                    Mode::OnePlayer => Mode::TwoPlayer,
+                    Mode::TwoPlayer => Mode::OnePlayer,
+                };
+            }
+            if key(Key::Right) {
+                self.cpu.difficulty = self.cpu.difficulty.harder();
+            }
+            if key(Key::Left) {
+                self.cpu.difficulty = self.cpu.difficulty.easier();
+            }
+            if ctx.input.key(Key::Space).went_down {
+                self.showing_instructions = false;
+            }
+            return;
+        }
+        if self.winner.is_some() {
+            self.blink_timer += dt;
+            if ctx.input.key(Key::Space).went_down {
+                self.start_match();
+            }
+            return;
+        }
+        self.prev_ball = self.ball;
+        self.prev_paddle_l = self.paddle_l;
+        self.prev_paddle_r = self.paddle_r;
+        physics::update_paddles(self, ctx, dt);
+        physics::update_ball(self, ctx, dt);
+    }
+}
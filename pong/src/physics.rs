@@ -1,18 +1,41 @@
+use arcade_kit::sound::{SoundEvent, emit};
 use glam::Vec2;
 use vectorcade_shared::{clamp, game::GameCtx, input::Key};
 
-use crate::Pong;
+use crate::{Mode, Pong, Side};
 
 const PADDLE_SPEED: f32 = 1.2;
 const PADDLE_HALF: f32 = 0.18;
+/// Half the paddle's thickness along x.
+const PADDLE_DEPTH: f32 = 0.02;
+const BALL_HALF: f32 = 0.02;
 const PADDLE_X_L: f32 = -0.9;
-const PADDLE_X_R: f32 = 0.9;
+pub(crate) const PADDLE_X_R: f32 = 0.9;
+pub(crate) const WALL_Y: f32 = 0.95;
+const SERVE_SPEED: f32 = 0.65;
+/// Serve angle is picked at random within this many radians of horizontal.
+const SERVE_SPREAD: f32 = 0.45;
+pub(crate) const SERVE_DELAY: f32 = 1.0;
+/// Speed multiplier applied on every paddle hit.
+const SPEED_UP: f32 = 1.06;
+const MAX_SPEED: f32 = 2.2;
+/// Return angle for a ball striking the very end of a paddle.
+const MAX_BOUNCE_ANGLE: f32 = std::f32::consts::FRAC_PI_3;
+const WIN_SCORE: u32 = 11;
+const WIN_MARGIN: u32 = 2;
 
-pub fn update_paddles(pong: &mut Pong, ctx: &GameCtx, dt: f32) {
-    let left_up = ctx.input.key(Key::W).is_down;
-    let left_dn = ctx.input.key(Key::S).is_down;
-    let right_up = ctx.input.key(Key::Up).is_down;
-    let right_dn = ctx.input.key(Key::Down).is_down;
+pub fn update_paddles(pong: &mut Pong, ctx: &mut GameCtx, dt: f32) {
+    let (up, down) = (ctx.input.key(Key::Up).is_down, ctx.input.key(Key::Down).is_down);
+    let mut left_up = ctx.input.key(Key::W).is_down;
+    let mut left_dn = ctx.input.key(Key::S).is_down;
+    let (mut right_up, mut right_dn) = (up, down);
+    if pong.mode == Mode::OnePlayer {
+        // Solo player may use either key pair; the CPU owns the right paddle
+        left_up |= up;
+        left_dn |= down;
+        (right_up, right_dn) = (false, false);
+        pong.cpu.update(&mut pong.paddle_r, pong.ball, pong.vel, ctx.rng, dt);
+    }
 
     if left_up {
         pong.paddle_l += PADDLE_SPEED * dt;
@@ -31,47 +54,151 @@ pub fn update_paddles(pong: &mut Pong, ctx: &GameCtx, dt: f32) {
     pong.paddle_r = clamp(pong.paddle_r, -0.8, 0.8);
 }
 
-pub fn update_ball(pong: &mut Pong, dt: f32) {
-    pong.ball += pong.vel * dt;
-    bounce_walls(pong);
-    bounce_paddles(pong);
-    check_scoring(pong);
+pub fn update_ball(pong: &mut Pong, ctx: &mut GameCtx, dt: f32) {
+    if pong.serve_timer > 0.0 {
+        pong.serve_timer -= dt;
+        if pong.serve_timer <= 0.0 {
+            serve(pong, ctx);
+        }
+        return;
+    }
+    move_ball(pong, ctx, dt);
+    bounce_walls(pong, ctx);
+    check_scoring(pong, ctx);
 }
 
-fn bounce_walls(pong: &mut Pong) {
-    if pong.ball.y > 0.95 {
-        pong.ball.y = 0.95;
+fn bounce_walls(pong: &mut Pong, ctx: &GameCtx) {
+    if pong.ball.y > WALL_Y {
+        pong.ball.y = WALL_Y;
         pong.vel.y = -pong.vel.y;
+        emit(ctx.audio, SoundEvent::WallBounce);
     }
-    if pong.ball.y < -0.95 {
-        pong.ball.y = -0.95;
+    if pong.ball.y < -WALL_Y {
+        pong.ball.y = -WALL_Y;
         pong.vel.y = -pong.vel.y;
+        emit(ctx.audio, SoundEvent::WallBounce);
     }
 }
 
-fn bounce_paddles(pong: &mut Pong) {
-    let hit_l = (pong.ball.x - PADDLE_X_L).abs() < 0.03
-        && (pong.ball.y - pong.paddle_l).abs() < PADDLE_HALF;
-    let hit_r = (pong.ball.x - PADDLE_X_R).abs() < 0.03
-        && (pong.ball.y - pong.paddle_r).abs() < PADDLE_HALF;
+/// First contact of a swept box, as returned by [`sweep_box`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SweepHit {
+    /// Fraction (0..=1) of the motion completed at contact.
+    pub t: f32,
+    /// Outward normal of the face that was struck.
+    pub normal: Vec2,
+}
 
-    if hit_l && pong.vel.x < 0.0 {
-        pong.vel.x = -pong.vel.x;
+/// Sweep a square of half-size `radius` from `pos` by `delta` against the box
+/// at `center` with half-extents `half`. A square that starts overlapping the
+/// box reports contact at `t = 0` on the face it most recently crossed. When
+/// both faces of a corner are reached at the same instant the x face wins.
+pub fn sweep_box(pos: Vec2, delta: Vec2, radius: f32, center: Vec2, half: Vec2) -> Option<SweepHit> {
+    let half = half + Vec2::splat(radius);
+    let (x_in, x_out) = slab(pos.x, delta.x, center.x, half.x)?;
+    let (y_in, y_out) = slab(pos.y, delta.y, center.y, half.y)?;
+    let t_in = x_in.max(y_in);
+    let t_out = x_out.min(y_out);
+    if t_in > t_out || t_in > 1.0 || t_out <= 0.0 {
+        return None;
     }
-    if hit_r && pong.vel.x > 0.0 {
-        pong.vel.x = -pong.vel.x;
+    let normal = if x_in >= y_in {
+        Vec2::new(-delta.x.signum(), 0.0)
+    } else {
+        Vec2::new(0.0, -delta.y.signum())
+    };
+    Some(SweepHit { t: t_in.max(0.0), normal })
+}
+
+/// Entry and exit times through one axis of an expanded box.
+fn slab(p: f32, d: f32, c: f32, h: f32) -> Option<(f32, f32)> {
+    if d == 0.0 {
+        return ((p - c).abs() <= h).then_some((f32::NEG_INFINITY, f32::INFINITY));
     }
+    let t1 = (c - h - p) / d;
+    let t2 = (c + h - p) / d;
+    Some((t1.min(t2), t1.max(t2)))
 }
 
-fn check_scoring(pong: &mut Pong) {
-    if pong.ball.x < -1.05 {
-        pong.score_r += 1;
-        pong.ball = Vec2::ZERO;
-        pong.vel = Vec2::new(0.6, 0.25);
+/// Move the ball one step, stopping at the first contact with the paddle it
+/// is heading for so fast balls cannot tunnel through.
+fn move_ball(pong: &mut Pong, ctx: &GameCtx, dt: f32) {
+    let delta = pong.vel * dt;
+    let (paddle, dir) = match pong.vel.x {
+        vx if vx < 0.0 => (Vec2::new(PADDLE_X_L, pong.paddle_l), 1.0),
+        vx if vx > 0.0 => (Vec2::new(PADDLE_X_R, pong.paddle_r), -1.0),
+        _ => {
+            pong.ball += delta;
+            return;
+        }
+    };
+    let half = Vec2::new(PADDLE_DEPTH, PADDLE_HALF);
+    let Some(hit) = sweep_box(pong.ball, delta, BALL_HALF, paddle, half) else {
+        pong.ball += delta;
+        return;
+    };
+    pong.ball += delta * hit.t;
+    if hit.normal.x == dir {
+        pong.vel = return_velocity(pong.vel, pong.ball.y - paddle.y, dir);
+    } else {
+        // Clipped the end of the paddle: glance off it and carry on past
+        pong.vel.y = hit.normal.y * pong.vel.y.abs();
     }
-    if pong.ball.x > 1.05 {
-        pong.score_l += 1;
-        pong.ball = Vec2::ZERO;
-        pong.vel = Vec2::new(-0.6, 0.25);
+    emit(ctx.audio, SoundEvent::PaddleHit);
+    pong.ball += pong.vel * dt * (1.0 - hit.t);
+}
+
+/// Velocity after a paddle hit: faster, and angled by how far off-centre
+/// (`offset` from the paddle middle) the ball struck. `dir` is the new x sign.
+pub(crate) fn return_velocity(vel: Vec2, offset: f32, dir: f32) -> Vec2 {
+    let speed = (vel.length() * SPEED_UP).min(MAX_SPEED);
+    let angle = (offset / PADDLE_HALF).clamp(-1.0, 1.0) * MAX_BOUNCE_ANGLE;
+    Vec2::new(dir * angle.cos(), angle.sin()) * speed
+}
+
+fn check_scoring(pong: &mut Pong, ctx: &GameCtx) {
+    let scorer = if pong.ball.x < -1.05 {
+        Side::Right
+    } else if pong.ball.x > 1.05 {
+        Side::Left
+    } else {
+        return;
+    };
+    match scorer {
+        Side::Left => pong.score_l += 1,
+        Side::Right => pong.score_r += 1,
+    }
+    emit(ctx.audio, SoundEvent::Score);
+    pong.ball = Vec2::ZERO;
+    // Don't blend across the jump back to centre
+    pong.prev_ball = pong.ball;
+    pong.vel = Vec2::ZERO;
+    pong.winner = match_winner(pong.score_l, pong.score_r);
+    if pong.winner.is_none() {
+        // Serves alternate regardless of who won the point
+        pong.serve_to = pong.serve_to.other();
+        pong.serve_timer = SERVE_DELAY;
+    }
+}
+
+/// Launch the ball from the centre toward `pong.serve_to`.
+fn serve(pong: &mut Pong, ctx: &mut GameCtx) {
+    let angle = ctx.rng.range_f32(-SERVE_SPREAD, SERVE_SPREAD);
+    let dir = match pong.serve_to {
+        Side::Left => -1.0,
+        Side::Right => 1.0,
+    };
+    pong.ball = Vec2::ZERO;
+    pong.vel = Vec2::new(dir * angle.cos(), angle.sin()) * SERVE_SPEED;
+}
+
+/// First to `WIN_SCORE`, but a player must lead by `WIN_MARGIN`.
+pub fn match_winner(score_l: u32, score_r: u32) -> Option<Side> {
+    if score_l >= WIN_SCORE && score_l >= score_r + WIN_MARGIN {
+        Some(Side::Left)
+    } else if score_r >= WIN_SCORE && score_r >= score_l + WIN_MARGIN {
+        Some(Side::Right)
+    } else {
+        None
     }
 }
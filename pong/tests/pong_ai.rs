@@ -0,0 +1,52 @@
+use glam::Vec2;
+use pong::{Difficulty, Mode, Pong, predict_intercept};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+#[test]
+fn intercept_straight_and_bounced() {
+    let y = predict_intercept(Vec2::ZERO, Vec2::new(1.0, 0.2), 0.9, 0.95).unwrap();
+    assert!((y - 0.18).abs() < 1e-5);
+    // One bounce off the top wall: 0.1 + 1.8 = 1.9 folds back to 0.0
+    let y = predict_intercept(Vec2::new(0.0, 0.1), Vec2::new(0.5, 1.0), 0.9, 0.95).unwrap();
+    assert!(y.abs() < 1e-5);
+    // Two bounces: -0.2 + 3.6 = 3.4, off the top then the bottom
+    let y = predict_intercept(Vec2::new(0.0, -0.2), Vec2::new(0.25, 1.0), 0.9, 0.95).unwrap();
+    assert!((y + 0.4).abs() < 1e-5);
+    assert!(predict_intercept(Vec2::ZERO, Vec2::new(-1.0, 0.0), 0.9, 0.95).is_none());
+}
+
+#[test]
+fn hard_cpu_returns_serves() {
+    let input = NoInput;
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(7);
+    let mut ctx = GameCtx {
+        input: &input,
+        audio: &audio,
+        rng: &mut rng,
+        screen: ScreenInfo::default(),
+        now_s: 0.0,
+    };
+    let mut g = Pong::new();
+    g.mode = Mode::OnePlayer;
+    g.cpu.difficulty = Difficulty::Hard;
+    g.showing_instructions = false;
+    // Fifteen seconds, including the opening serve delay
+    for _ in 0..900 {
+        g.update(&mut ctx, 1.0 / 60.0);
+    }
+    assert_eq!(g.score_l, 0, "CPU should not concede against an idle human");
+    assert!(g.score_r > 0);
+}
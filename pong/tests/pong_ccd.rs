@@ -0,0 +1,148 @@
+use glam::Vec2;
+use pong::{Pong, sweep_box};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+const PADDLE: Vec2 = Vec2::new(0.9, 0.0);
+const HALF: Vec2 = Vec2::new(0.02, 0.18);
+
+#[test]
+fn sweep_finds_exact_time_of_impact() {
+    // Front face of the expanded box is at x = 0.86
+    let hit = sweep_box(Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0), 0.02, PADDLE, HALF).unwrap();
+    assert!((hit.t - 0.43).abs() < 1e-5);
+    assert_eq!(hit.normal, Vec2::new(-1.0, 0.0));
+    assert!(sweep_box(Vec2::new(0.0, 0.0), Vec2::new(0.5, 0.0), 0.02, PADDLE, HALF).is_none());
+    assert!(sweep_box(Vec2::new(0.0, 0.5), Vec2::new(2.0, 0.0), 0.02, PADDLE, HALF).is_none());
+}
+
+#[test]
+fn sweep_handles_corners() {
+    // Dropping onto the top of the paddle strikes the top face
+    let hit = sweep_box(Vec2::new(0.9, 0.5), Vec2::new(0.0, -1.0), 0.02, PADDLE, HALF).unwrap();
+    assert_eq!(hit.normal, Vec2::new(0.0, 1.0));
+    assert!((hit.t - 0.3).abs() < 1e-5);
+    // Grazing past the top corner while rising misses
+    assert!(sweep_box(Vec2::new(0.5, 0.2), Vec2::new(0.6, 0.6), 0.02, PADDLE, HALF).is_none());
+    // Arriving exactly at a corner along the diagonal (values exact in binary)
+    let hit = sweep_box(Vec2::new(0.0, 1.0), Vec2::new(1.0, -1.0), 0.25, Vec2::new(1.0, 0.0), Vec2::splat(0.25)).unwrap();
+    assert_eq!(hit.t, 0.5);
+    assert_eq!(hit.normal, Vec2::new(-1.0, 0.0), "exact corner counts as the front face");
+    // Coming in steeply from above lands on the top face, not the front
+    let hit = sweep_box(Vec2::new(0.85, 0.5), Vec2::new(0.05, -0.5), 0.02, PADDLE, HALF).unwrap();
+    assert_eq!(hit.normal, Vec2::new(0.0, 1.0));
+}
+
+#[test]
+fn sweep_reports_overlap_at_start() {
+    // A paddle that moved onto the ball still returns it
+    let hit = sweep_box(Vec2::new(0.87, 0.1), Vec2::new(0.1, 0.0), 0.02, PADDLE, HALF).unwrap();
+    assert_eq!(hit.t, 0.0);
+    assert_eq!(hit.normal, Vec2::new(-1.0, 0.0));
+}
+
+/// Serve a ball from `start` at `vel` and step the game at 120 Hz until its
+/// velocity changes direction on either axis, or `steps` run out.
+fn play_shot(start: Vec2, vel: Vec2, steps: usize) -> Pong {
+    let input = NoInput;
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(1);
+    let mut ctx = GameCtx {
+        input: &input,
+        audio: &audio,
+        rng: &mut rng,
+        screen: ScreenInfo::default(),
+        now_s: 0.0,
+    };
+    let mut g = Pong::new();
+    g.showing_instructions = false;
+    g.serve_timer = 0.0;
+    g.ball = start;
+    g.vel = vel;
+    for _ in 0..steps {
+        g.update(&mut ctx, 1.0 / 120.0);
+        if g.vel.x.signum() != vel.x.signum() || g.vel.y.signum() != vel.y.signum() {
+            break;
+        }
+    }
+    g
+}
+
+#[test]
+fn fast_balls_never_tunnel() {
+    let input = NoInput;
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(1);
+    let mut ctx = GameCtx {
+        input: &input,
+        audio: &audio,
+        rng: &mut rng,
+        screen: ScreenInfo::default(),
+        now_s: 0.0,
+    };
+    // `side` mirrors every shot onto the left paddle
+    for side in [1.0, -1.0] {
+        for speed in [3.0, 8.0, 20.0, 40.0] {
+            for start in [(-0.5, -0.8), (-0.5, 0.8), (0.0, 0.0), (0.5, -0.8), (0.5, 0.8), (0.7, 0.5)] {
+                for i in -10..=10 {
+                    // Aim along the whole face, out to the ends; from the near
+                    // starts that is up to about 70 degrees off the x axis
+                    let start = Vec2::new(start.0 * side, start.1);
+                    let target = Vec2::new(0.86 * side, i as f32 * 0.019);
+                    let mut g = Pong::new();
+                    g.showing_instructions = false;
+                    g.serve_timer = 0.0;
+                    g.ball = start;
+                    g.vel = (target - start).normalize() * speed;
+                    for _ in 0..30 {
+                        g.update(&mut ctx, 1.0 / 30.0);
+                        if g.vel.x * side < 0.0 {
+                            break;
+                        }
+                    }
+                    let shot = format!("side {side} speed {speed} from {start:?} aim {i}");
+                    assert!(g.vel.x * side < 0.0, "{shot}: ball tunnelled to {:?}", g.ball);
+                    assert_eq!((g.score_l, g.score_r), (0, 0), "{shot}");
+                    assert!(g.ball.x * side <= 0.86 + 1e-4, "{shot}: ball at {:?}", g.ball);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn corner_hits_reflect_correctly() {
+    for side in [1.0, -1.0] {
+        for up in [1.0, -1.0] {
+            let at = |x: f32, y: f32| Vec2::new(x * side, y * up);
+            let case = format!("side {side} up {up}");
+
+            // Dropping steeply onto the end of the paddle glances off that end
+            // and carries on towards the goal line
+            let g = play_shot(at(0.9, 0.5), at(0.6, -6.0), 120);
+            assert!(g.vel.y * up > 0.0, "{case}: end hit should bounce away, vel {:?}", g.vel);
+            assert!(g.vel.x * side > 0.0, "{case}: end hit should carry on, vel {:?}", g.vel);
+            assert!(g.ball.y * up >= 0.2 - 1e-4, "{case}: ball inside paddle at {:?}", g.ball);
+
+            // Clipping the front face right by the corner returns the ball at
+            // the steepest angle, away from the paddle middle
+            let start = at(0.4, 0.1);
+            let g = play_shot(start, (at(0.86, 0.19) - start).normalize() * 8.0, 120);
+            assert!(g.vel.x * side < 0.0, "{case}: front corner should return, vel {:?}", g.vel);
+            let dir = g.vel.normalize();
+            assert!(dir.y * up > 0.85, "{case}: return should be angled out, dir {dir:?}");
+            assert!(g.ball.x * side <= 0.86 + 1e-4, "{case}: ball inside paddle at {:?}", g.ball);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use glam::Vec2;
+use pong::Pong;
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::draw::DrawCmd;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+/// Centre of the ball as drawn: the fourth outline after the centre line
+/// and the two paddles.
+fn drawn_ball(out: &[DrawCmd]) -> Vec2 {
+    let pts = out
+        .iter()
+        .filter_map(|c| match c {
+            DrawCmd::Polyline { pts, .. } => Some(pts),
+            _ => None,
+        })
+        .nth(3)
+        .expect("ball outline");
+    let (min, max) = pts.iter().fold((Vec2::MAX, Vec2::MIN), |(lo, hi), &p| (lo.min(p), hi.max(p)));
+    (min + max) / 2.0
+}
+
+#[test]
+fn ball_moves_smoothly_between_steps() {
+    let mut rng = Xorshift64::new(1);
+    let mut ctx = GameCtx { input: &NoInput, audio: &NoAudio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = Pong::new();
+    g.showing_instructions = false;
+    g.serve_timer = 0.0;
+    g.vel = Vec2::new(0.6, 0.0);
+    // 144 Hz display over a 120 Hz simulation: some frames run no step at
+    // all, yet the drawn ball should advance on every one of them
+    let mut last = f32::MIN;
+    for frame in 0..30 {
+        g.update(&mut ctx, 1.0 / 144.0);
+        let mut out = Vec::new();
+        g.render(&mut ctx, &mut out);
+        let x = drawn_ball(&out).x;
+        assert!(x > last, "frame {frame}: ball drawn at {x}, was {last}");
+        assert!(x >= g.prev_ball.x - 1e-6 && x <= g.ball.x + 1e-6);
+        last = x;
+    }
+}
@@ -0,0 +1,128 @@
+use glam::Vec2;
+use pong::{Pong, Side, match_winner};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct Keys(&'static [Key]);
+impl InputState for Keys {
+    fn key(&self, k: Key) -> Button {
+        let down = self.0.contains(&k);
+        Button { is_down: down, went_down: down, went_up: false }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+fn run(g: &mut Pong, keys: &'static [Key], dt: f32) {
+    let input = Keys(keys);
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(3);
+    let mut ctx = GameCtx {
+        input: &input,
+        audio: &audio,
+        rng: &mut rng,
+        screen: ScreenInfo::default(),
+        now_s: 0.0,
+    };
+    g.update(&mut ctx, dt);
+}
+
+/// A match already past its opening serve, so tests can place the ball.
+fn playing() -> Pong {
+    let mut g = Pong::new();
+    g.showing_instructions = false;
+    g.serve_timer = 0.0;
+    g
+}
+
+#[test]
+fn win_at_eleven_by_two() {
+    assert_eq!(match_winner(11, 9), Some(Side::Left));
+    assert_eq!(match_winner(11, 10), None);
+    assert_eq!(match_winner(12, 10), Some(Side::Left));
+    assert_eq!(match_winner(13, 15), Some(Side::Right));
+    assert_eq!(match_winner(10, 0), None);
+}
+
+#[test]
+fn paddle_hit_speeds_up_and_angles_return() {
+    let mut g = playing();
+    g.ball = Vec2::new(-0.88, 0.12);
+    g.vel = Vec2::new(-0.6, 0.0);
+    run(&mut g, &[], 1.0 / 120.0);
+    assert!(g.vel.x > 0.0);
+    assert!(g.vel.y > 0.0, "upper half of the paddle sends the ball up");
+    assert!(g.vel.length() > 0.6);
+
+    let mut flat = playing();
+    flat.ball = Vec2::new(-0.88, 0.0);
+    flat.vel = Vec2::new(-0.6, 0.0);
+    run(&mut flat, &[], 1.0 / 120.0);
+    assert!(flat.vel.y.abs() < 1e-3, "centre hit returns flat");
+    assert!(g.vel.y / g.vel.x > flat.vel.y / flat.vel.x);
+}
+
+#[test]
+fn serves_alternate_after_a_delay() {
+    let mut g = playing();
+    g.ball = Vec2::new(1.04, 0.0);
+    g.vel = Vec2::new(0.6, 0.0);
+    run(&mut g, &[], 0.05);
+    assert_eq!(g.score_l, 1);
+    assert_eq!(g.vel, Vec2::ZERO, "ball waits before the serve");
+    run(&mut g, &[], 0.25);
+    run(&mut g, &[], 0.25);
+    assert_eq!(g.ball, Vec2::ZERO);
+    run(&mut g, &[], 0.25);
+    run(&mut g, &[], 0.25);
+    run(&mut g, &[], 0.1);
+    assert!(g.vel.x < 0.0, "second serve goes left");
+
+    g.ball = Vec2::new(-1.04, 0.0);
+    g.vel = Vec2::new(-0.6, 0.0);
+    run(&mut g, &[], 0.05);
+    for _ in 0..5 {
+        run(&mut g, &[], 0.25);
+    }
+    assert_eq!(g.score_r, 1);
+    assert!(g.vel.x > 0.0, "third serve goes right");
+}
+
+#[test]
+fn game_over_then_rematch() {
+    let mut g = playing();
+    g.score_l = 10;
+    g.score_r = 8;
+    g.ball = Vec2::new(1.04, 0.0);
+    g.vel = Vec2::new(0.6, 0.0);
+    run(&mut g, &[], 0.05);
+    assert_eq!(g.winner, Some(Side::Left));
+    run(&mut g, &[], 0.25);
+    assert_eq!(g.ball, Vec2::ZERO, "play stops at game over");
+    run(&mut g, &[Key::Space], 0.05);
+    assert_eq!(g.winner, None);
+    assert_eq!((g.score_l, g.score_r), (0, 0));
+    assert_eq!(g.vel, Vec2::ZERO, "rematch waits for its opening serve");
+}
+
+#[test]
+fn opening_serve_waits_then_launches() {
+    let mut g = Pong::new();
+    g.showing_instructions = false;
+    let delay = g.serve_timer;
+    assert!(delay > 0.0);
+    let mut waited = 0.0;
+    while waited + 0.05 < delay {
+        run(&mut g, &[], 0.05);
+        waited += 0.05;
+        assert_eq!(g.ball, Vec2::ZERO, "ball moved {waited}s into the serve delay");
+        assert_eq!(g.vel, Vec2::ZERO);
+    }
+    run(&mut g, &[], 0.1);
+    assert!(g.vel.x > 0.0, "opening serve goes right");
+    assert!((g.vel.y / g.vel.x).abs() < 0.5, "serve stays within the spread");
+}
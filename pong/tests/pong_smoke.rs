@@ -1,4 +1,6 @@
 use pong::Pong;
+use arcade_kit::sound::{SoundEvent, SoundLog};
+use glam::Vec2;
 use vectorcade_shared::Xorshift64;
 use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
 use vectorcade_shared::input::{Axis, Button, InputState, Key};
@@ -28,6 +30,38 @@ fn pong_advances() {
     let mut g = Pong::new();
     g.showing_instructions = false; // Skip instructions for test
     let x0 = g.ball.x;
-    g.update(&mut ctx, 0.016);
+    // Past the opening serve delay
+    for _ in 0..5 {
+        g.update(&mut ctx, 0.25);
+    }
     assert!(g.ball.x != x0);
 }
+
+#[test]
+fn pong_emits_sounds() {
+    let input = NoInput;
+    let audio = SoundLog::new();
+    let mut rng = Xorshift64::new(12345);
+    let mut ctx = GameCtx {
+        input: &input,
+        audio: &audio,
+        rng: &mut rng,
+        screen: ScreenInfo::default(),
+        now_s: 0.0,
+    };
+    let mut g = Pong::new();
+    g.showing_instructions = false;
+    g.serve_timer = 0.0;
+    g.ball = Vec2::new(-0.85, 0.0);
+    g.vel = Vec2::new(-0.6, 0.0);
+    g.update(&mut ctx, 0.1);
+    assert!(audio.contains(SoundEvent::PaddleHit));
+    g.ball = Vec2::new(0.0, 0.94);
+    g.vel = Vec2::new(0.1, 0.6);
+    g.update(&mut ctx, 0.05);
+    assert!(audio.contains(SoundEvent::WallBounce));
+    g.ball = Vec2::new(-1.04, 0.5);
+    g.vel = Vec2::new(-0.6, 0.0);
+    g.update(&mut ctx, 0.05);
+    assert_eq!(audio.count(SoundEvent::Score), 1);
+}
@@ -1,7 +1,13 @@
 //! Enemy types for Tempest.
 
+use arcade_kit::sound::{SoundEvent, emit};
+use vectorcade_shared::game::GameCtx;
+
 use crate::tube::Tube;
 
+/// Seconds a flipper climbs before flipping to the next segment.
+const FLIP_INTERVAL: f32 = 0.8;
+
 /// Enemy types matching original arcade.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum EnemyKind {
@@ -32,20 +38,33 @@ impl EnemyKind {
 pub struct Enemy {
     pub segment: usize,
     pub depth: f32,
+    /// Lane and depth before the latest step, for drawing.
+    pub prev_segment: usize,
+    pub prev_depth: f32,
     pub kind: EnemyKind,
     pub alive: bool,
+    pub flip_timer: f32,
 }
 
 impl Enemy {
     pub fn new(segment: usize, kind: EnemyKind) -> Self {
-        Self { segment, depth: 1.0, kind, alive: true }
+        Self { segment, depth: 1.0, prev_segment: segment, prev_depth: 1.0, kind, alive: true, flip_timer: FLIP_INTERVAL }
     }
 }
 
-/// Update all enemies - move toward rim.
-pub fn update_enemies(enemies: &mut [Enemy], _tube: &Tube, dt: f32) {
+/// Update all enemies - move toward rim, flippers flip toward the player.
+pub fn update_enemies(enemies: &mut [Enemy], tube: &Tube, target: usize, ctx: &GameCtx, dt: f32) {
     for e in enemies.iter_mut() {
         if !e.alive { continue; }
         e.depth -= e.kind.speed() * dt;
+        if e.kind != EnemyKind::Flipper || e.segment == target { continue; }
+        e.flip_timer -= dt;
+        if e.flip_timer <= 0.0 {
+            e.flip_timer = FLIP_INTERVAL;
+            let segs = tube.segments;
+            let ahead = (target + segs - e.segment) % segs;
+            e.segment = if ahead <= segs / 2 { (e.segment + 1) % segs } else { (e.segment + segs - 1) % segs };
+            emit(ctx.audio, SoundEvent::FlipperFlip);
+        }
     }
 }
@@ -7,6 +7,10 @@ mod enemies;
 mod rendering;
 mod tube;
 
+use arcade_kit::{
+    sound::{SoundEvent, emit},
+    timestep::{FixedStep, FixedUpdate, run_fixed},
+};
 use vectorcade_shared::{
     Rgba,
     draw::DrawCmd,
@@ -15,7 +19,7 @@ use vectorcade_shared::{
     input::Key,
 };
 
-use enemies::{Enemy, EnemyKind};
+pub use enemies::{Enemy, EnemyKind};
 use tube::Tube;
 
 /// Colors matching original Tempest arcade.
@@ -33,6 +37,9 @@ pub enum GameState { Instructions, Playing, GameOver, LevelComplete }
 /// Player's blaster on the tube rim.
 pub struct Blaster {
     pub segment: usize,
+    /// Segment before the latest step; `render` slides the claw from it by
+    /// the clock's `alpha`.
+    pub prev_segment: usize,
     pub fire_cooldown: f32,
 }
 
@@ -40,6 +47,8 @@ pub struct Blaster {
 pub struct Shot {
     pub segment: usize,
     pub depth: f32,
+    /// Depth before the latest step, for drawing.
+    pub prev_depth: f32,
     pub alive: bool,
 }
 
@@ -56,6 +65,7 @@ pub struct Tempest {
     pub spawn_timer: f32,
     pub font_style: FontStyleId,
     pub blink_timer: f32,
+    pub clock: FixedStep,
 }
 
 impl Default for Tempest {
@@ -66,7 +76,7 @@ impl Tempest {
     pub fn new() -> Self {
         Self {
             tube: Tube::circle(16),
-            blaster: Blaster { segment: 0, fire_cooldown: 0.0 },
+            blaster: Blaster { segment: 0, prev_segment: 0, fire_cooldown: 0.0 },
             shots: Vec::new(),
             enemies: Vec::new(),
             score: 0,
@@ -76,6 +86,7 @@ impl Tempest {
             spawn_timer: 0.0,
             font_style: FontStyleId::ATARI,
             blink_timer: 0.0,
+            clock: FixedStep::default(),
         }
     }
 
@@ -97,7 +108,7 @@ impl Game for Tempest {
 
     fn reset(&mut self, _ctx: &mut GameCtx) {
         self.tube = Tube::circle(16);
-        self.blaster = Blaster { segment: 0, fire_cooldown: 0.0 };
+        self.blaster = Blaster { segment: 0, prev_segment: 0, fire_cooldown: 0.0 };
         self.shots.clear();
         self.enemies.clear();
         self.score = 0;
@@ -106,9 +117,43 @@ impl Game for Tempest {
         self.state = GameState::Instructions;
         self.spawn_timer = 0.0;
         self.blink_timer = 0.0;
+        self.clock.reset();
     }
 
     fn update(&mut self, ctx: &mut GameCtx, dt: f32) {
+        run_fixed(self, ctx, dt);
+    }
+
+    fn render(&mut self, _ctx: &mut GameCtx, out: &mut Vec<DrawCmd>) {
+        out.push(DrawCmd::Clear { color: Rgba::BLACK });
+        if self.state == GameState::Instructions {
+            rendering::render_instructions(out, self.font_style, self.blink_timer);
+            return;
+        }
+        let alpha = self.clock.alpha();
+        rendering::render_tube(out, &self.tube);
+        rendering::render_blaster(out, &self.tube, &self.blaster, alpha);
+        rendering::render_shots(out, &self.tube, &self.shots, alpha);
+        rendering::render_enemies(out, &self.tube, &self.enemies, alpha);
+        rendering::render_hud(out, self.score, self.lives, self.level, self.font_style);
+        if self.state == GameState::GameOver {
+            rendering::render_game_over(out, self.font_style);
+        }
+    }
+}
+
+impl FixedUpdate for Tempest {
+    fn clock(&mut self) -> &mut FixedStep { &mut self.clock }
+
+    fn fixed_update(&mut self, ctx: &mut GameCtx, dt: f32) {
+        self.blaster.prev_segment = self.blaster.segment;
+        for shot in &mut self.shots {
+            shot.prev_depth = shot.depth;
+        }
+        for e in &mut self.enemies {
+            e.prev_segment = e.segment;
+            e.prev_depth = e.depth;
+        }
         if self.state == GameState::Instructions {
             self.blink_timer += dt;
             if ctx.input.key(Key::Space).went_down { self.state = GameState::Playing; }
@@ -116,9 +161,9 @@ impl Game for Tempest {
         }
         if self.state == GameState::GameOver || self.state == GameState::LevelComplete { return; }
         update_player(self, ctx, dt);
-        enemies::update_enemies(&mut self.enemies, &self.tube, dt);
+        enemies::update_enemies(&mut self.enemies, &self.tube, self.blaster.segment, ctx, dt);
         update_shots(self, dt);
-        check_collisions(self);
+        check_collisions(self, ctx);
         self.spawn_timer -= dt;
         if self.spawn_timer <= 0.0 && self.enemies.len() < 6 {
             self.spawn_enemy(ctx);
@@ -128,22 +173,6 @@ impl Game for Tempest {
             self.state = GameState::LevelComplete;
         }
     }
-
-    fn render(&mut self, _ctx: &mut GameCtx, out: &mut Vec<DrawCmd>) {
-        out.push(DrawCmd::Clear { color: Rgba::BLACK });
-        if self.state == GameState::Instructions {
-            rendering::render_instructions(out, self.font_style, self.blink_timer);
-            return;
-        }
-        rendering::render_tube(out, &self.tube);
-        rendering::render_blaster(out, &self.tube, &self.blaster);
-        rendering::render_shots(out, &self.tube, &self.shots);
-        rendering::render_enemies(out, &self.tube, &self.enemies);
-        rendering::render_hud(out, self.score, self.lives, self.level, self.font_style);
-        if self.state == GameState::GameOver {
-            rendering::render_game_over(out, self.font_style);
-        }
-    }
 }
 
 fn update_player(game: &mut Tempest, ctx: &GameCtx, dt: f32) {
@@ -155,7 +184,8 @@ fn update_player(game: &mut Tempest, ctx: &GameCtx, dt: f32) {
     game.blaster.fire_cooldown -= dt;
     if ctx.input.key(Key::Space).is_down && game.blaster.fire_cooldown <= 0.0 {
         game.blaster.fire_cooldown = 0.15;
-        game.shots.push(Shot { segment: game.blaster.segment, depth: 0.0, alive: true });
+        game.shots.push(Shot { segment: game.blaster.segment, depth: 0.0, prev_depth: 0.0, alive: true });
+        emit(ctx.audio, SoundEvent::BulletFire);
     }
 }
 
@@ -167,7 +197,7 @@ fn update_shots(game: &mut Tempest, dt: f32) {
     game.shots.retain(|s| s.alive);
 }
 
-fn check_collisions(game: &mut Tempest) {
+fn check_collisions(game: &mut Tempest, ctx: &GameCtx) {
     // Shots hitting enemies
     for shot in &mut game.shots {
         for enemy in &mut game.enemies {
@@ -175,6 +205,7 @@ fn check_collisions(game: &mut Tempest) {
                 shot.alive = false;
                 enemy.alive = false;
                 game.score += enemy.kind.points();
+                emit(ctx.audio, SoundEvent::EnemyDestroyed);
             }
         }
     }
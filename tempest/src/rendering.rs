@@ -50,9 +50,12 @@ pub fn render_tube(out: &mut Vec<DrawCmd>, tube: &Tube) {
     });
 }
 
-/// Render the player's blaster.
-pub fn render_blaster(out: &mut Vec<DrawCmd>, tube: &Tube, blaster: &Blaster) {
-    let (a, b) = tube.segment_edges(blaster.segment);
+/// Render the player's blaster, `alpha` of the way from its previous
+/// segment to its current one.
+pub fn render_blaster(out: &mut Vec<DrawCmd>, tube: &Tube, blaster: &Blaster, alpha: f32) {
+    let (a0, b0) = tube.segment_edges(blaster.prev_segment);
+    let (a1, b1) = tube.segment_edges(blaster.segment);
+    let (a, b) = (a0.lerp(a1, alpha), b0.lerp(b1, alpha));
     let mid = (a + b) * 0.5;
     let inward = (tube.center - mid).normalize() * 0.05;
     // Claw shape
@@ -60,11 +63,12 @@ pub fn render_blaster(out: &mut Vec<DrawCmd>, tube: &Tube, blaster: &Blaster) {
     out.push(DrawCmd::Polyline { pts, closed: false, stroke: Stroke::new(YELLOW, 3.0) });
 }
 
-/// Render player shots.
-pub fn render_shots(out: &mut Vec<DrawCmd>, tube: &Tube, shots: &[Shot]) {
+/// Render player shots, blended `alpha` of the way through the latest step.
+pub fn render_shots(out: &mut Vec<DrawCmd>, tube: &Tube, shots: &[Shot], alpha: f32) {
     for shot in shots {
-        let pos = tube.point_at(shot.segment, shot.depth);
-        let size = 0.02 * (1.0 - shot.depth * 0.5);
+        let depth = shot.prev_depth + (shot.depth - shot.prev_depth) * alpha;
+        let pos = tube.point_at(shot.segment, depth);
+        let size = 0.02 * (1.0 - depth * 0.5);
         out.push(DrawCmd::Polyline {
             pts: vec![pos - Vec2::new(size, 0.0), pos + Vec2::new(size, 0.0)],
             closed: false,
@@ -73,12 +77,14 @@ pub fn render_shots(out: &mut Vec<DrawCmd>, tube: &Tube, shots: &[Shot]) {
     }
 }
 
-/// Render enemies.
-pub fn render_enemies(out: &mut Vec<DrawCmd>, tube: &Tube, enemies: &[Enemy]) {
+/// Render enemies, blended `alpha` of the way through the latest step; a
+/// flipper changing lanes slides across.
+pub fn render_enemies(out: &mut Vec<DrawCmd>, tube: &Tube, enemies: &[Enemy], alpha: f32) {
     for enemy in enemies {
         if !enemy.alive { continue; }
-        let pos = tube.point_at(enemy.segment, enemy.depth);
-        let size = 0.03 * (1.0 - enemy.depth * 0.5);
+        let from = tube.point_at(enemy.prev_segment, enemy.prev_depth);
+        let pos = from.lerp(tube.point_at(enemy.segment, enemy.depth), alpha);
+        let size = 0.03 * (1.0 - (enemy.prev_depth + (enemy.depth - enemy.prev_depth) * alpha) * 0.5);
         let color = match enemy.kind {
             EnemyKind::Flipper => RED,
             EnemyKind::Tanker => GREEN,
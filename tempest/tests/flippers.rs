@@ -0,0 +1,37 @@
+use arcade_kit::sound::{SoundEvent, SoundLog};
+use tempest::{Enemy, EnemyKind, GameState, Tempest};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+struct NoInput;
+impl InputState for NoInput {
+    fn key(&self, _k: Key) -> Button { Button::UP }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+#[test]
+fn flippers_flip_the_short_way_toward_the_player() {
+    let audio = SoundLog::new();
+    let mut rng = Xorshift64::new(12345);
+    let mut ctx = GameCtx { input: &NoInput, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    let mut g = Tempest::new();
+    g.reset(&mut ctx);
+    g.state = GameState::Playing;
+    g.spawn_timer = 1000.0;
+    g.blaster.segment = 0;
+    g.enemies = vec![
+        Enemy::new(4, EnemyKind::Flipper),
+        Enemy::new(12, EnemyKind::Flipper),
+        Enemy::new(0, EnemyKind::Flipper),
+        Enemy::new(4, EnemyKind::Tanker),
+    ];
+    // One flip interval
+    for _ in 0..4 {
+        g.update(&mut ctx, 0.25);
+    }
+    let segments: Vec<usize> = g.enemies.iter().map(|e| e.segment).collect();
+    assert_eq!(segments, [3, 13, 0, 4], "down from 4, up from 12 across the wrap, none on the player's lane, tankers stay");
+    assert_eq!(audio.count(SoundEvent::FlipperFlip), 2);
+}
@@ -1,4 +1,5 @@
 use tempest::{Tempest, GameState};
+use arcade_kit::sound::{SoundEvent, SoundLog};
 use vectorcade_shared::Xorshift64;
 use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
 use vectorcade_shared::input::{Axis, Button, InputState, Key};
@@ -31,3 +32,23 @@ fn tempest_advances() {
     assert_eq!(g.tube.segments, 16, "should have 16 tube segments");
     g.update(&mut ctx, 0.016);
 }
+
+#[test]
+fn tempest_emits_sounds() {
+    let input = NoInput;
+    let audio = SoundLog::new();
+    let mut rng = Xorshift64::new(12345);
+    let mut ctx = GameCtx {
+        input: &input,
+        audio: &audio,
+        rng: &mut rng,
+        screen: ScreenInfo::default(),
+        now_s: 0.0,
+    };
+    let mut g = Tempest::new();
+    g.reset(&mut ctx);
+    g.state = GameState::Playing;
+    for _ in 0..600 { g.update(&mut ctx, 0.016); }
+    assert!(audio.contains(SoundEvent::FlipperFlip), "flippers flip toward the player");
+    assert!(!audio.contains(SoundEvent::BulletFire), "no shots without input");
+}
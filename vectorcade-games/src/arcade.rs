@@ -0,0 +1,90 @@
+//! Launcher that switches between the contents menu and the games.
+
+use contents::Contents;
+use vectorcade_shared::{
+    draw::DrawCmd,
+    game::{Game, GameCtx, GameMeta},
+    input::Key,
+};
+
+use crate::{GameEntry, contents_menu, games, lookup};
+
+/// Hosts the contents menu and launches games from the registry on demand.
+///
+/// Enter/Space on the menu builds and starts the highlighted game;
+/// Escape from any game drops it and returns to the menu.
+pub struct Arcade {
+    pub menu: Contents,
+    active: Option<(&'static GameEntry, Box<dyn Game + Send>)>,
+}
+
+impl Default for Arcade {
+    fn default() -> Self { Self::new() }
+}
+
+impl Arcade {
+    pub fn new() -> Self {
+        Self { menu: contents_menu(), active: None }
+    }
+
+    /// Registry ID of the running game, or `None` while on the menu.
+    pub fn active_id(&self) -> Option<&'static str> {
+        self.active.as_ref().map(|(entry, _)| entry.id)
+    }
+
+    /// Start a game directly by registry ID, skipping the menu; the
+    /// contents ID goes back to the menu. Returns `false` if the ID is unknown.
+    pub fn launch(&mut self, id: &str, ctx: &mut GameCtx) -> bool {
+        let Some(entry) = lookup(id) else { return false };
+        if games().iter().any(|g| std::ptr::eq(g, entry)) {
+            self.start(entry, ctx);
+        } else {
+            self.active = None;
+            self.menu.reset(ctx);
+        }
+        true
+    }
+
+    fn start(&mut self, entry: &'static GameEntry, ctx: &mut GameCtx) {
+        let mut game = entry.create();
+        game.reset(ctx);
+        self.active = Some((entry, game));
+    }
+}
+
+impl Game for Arcade {
+    fn metadata(&self) -> GameMeta {
+        match &self.active {
+            Some((_, game)) => game.metadata(),
+            None => self.menu.metadata(),
+        }
+    }
+
+    fn reset(&mut self, ctx: &mut GameCtx) {
+        self.active = None;
+        self.menu.reset(ctx);
+    }
+
+    fn update(&mut self, ctx: &mut GameCtx, dt: f32) {
+        if let Some((_, game)) = &mut self.active {
+            if ctx.input.key(Key::Escape).went_down {
+                self.active = None;
+                self.menu.reset(ctx);
+                return;
+            }
+            game.update(ctx, dt);
+            return;
+        }
+        self.menu.update(ctx, dt);
+        if let Some(i) = self.menu.take_launch() {
+            self.start(&games()[i], ctx);
+        }
+    }
+
+    fn render(&mut self, ctx: &mut GameCtx, out: &mut Vec<DrawCmd>) {
+        match &mut self.active {
+            Some((_, game)) => game.render(ctx, out),
+            None => self.menu.render(ctx, out),
+        }
+    }
+}
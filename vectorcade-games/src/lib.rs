@@ -1,15 +1,20 @@
 //! Registry / facade over available games.
 
+mod arcade;
+mod registry;
+
+pub use arcade::Arcade;
+pub use arcade_kit::replay;
+pub use registry::{GameEntry, create, games, lookup, registry};
+
 use vectorcade_shared::game::Game;
 
+/// Contents menu listing the registered games by name.
+pub fn contents_menu() -> contents::Contents {
+    contents::Contents::with_entries(games().iter().map(|e| e.name).collect())
+}
+
+/// Construct every registered game, contents screen first.
 pub fn all_games() -> Vec<Box<dyn Game + Send>> {
-    vec![
-        Box::new(contents::Contents::new()),
-        Box::new(chess_demo::ChessDemo::new()),
-        Box::new(pong::Pong::new()),
-        Box::new(asteroids::Asteroids::new()),
-        Box::new(lunar_lander::LunarLander::new()),
-        Box::new(battlezone::Battlezone::new()),
-        Box::new(tempest::Tempest::new()),
-    ]
+    registry().map(GameEntry::create).collect()
 }
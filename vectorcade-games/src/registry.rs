@@ -0,0 +1,104 @@
+//! Static game registry with stable IDs and lazy factories.
+
+use vectorcade_shared::game::Game;
+
+/// Registry entry describing one launchable game.
+pub struct GameEntry {
+    /// Stable identifier for deep links and saved settings (e.g. `"asteroids"`).
+    pub id: &'static str,
+    /// Display name; matches the game's `GameMeta::name`.
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Maximum number of simultaneous players.
+    pub players: u8,
+    /// One-line control summary for shells and menus.
+    pub controls: &'static str,
+    pub factory: fn() -> Box<dyn Game + Send>,
+}
+
+impl GameEntry {
+    /// Build a fresh instance of this game.
+    pub fn create(&self) -> Box<dyn Game + Send> {
+        (self.factory)()
+    }
+}
+
+static CONTENTS: GameEntry = GameEntry {
+    id: "contents",
+    name: "Contents",
+    description: "Game selection menu",
+    players: 1,
+    controls: "UP/DOWN select, ENTER play",
+    factory: || Box::new(crate::contents_menu()),
+};
+
+static GAMES: [GameEntry; 6] = [
+    GameEntry {
+        id: "chess-demo",
+        name: "Chess Image",
+        description: "Static vector chess board",
+        players: 0,
+        controls: "None",
+        factory: || Box::new(chess_demo::ChessDemo::new()),
+    },
+    GameEntry {
+        id: "pong",
+        name: "Pong",
+        description: "Classic table tennis against a friend or the CPU",
+        players: 2,
+        controls: "W/S left paddle, UP/DOWN right paddle (either in 1P)",
+        factory: || Box::new(pong::Pong::new()),
+    },
+    GameEntry {
+        id: "asteroids",
+        name: "Asteroids",
+        description: "Blast drifting rocks in a wrap-around field",
+        players: 1,
+        controls: "LEFT/RIGHT rotate, UP thrust, SPACE fire, DOWN hyperspace",
+        factory: || Box::new(asteroids::Asteroids::new()),
+    },
+    GameEntry {
+        id: "lunar-lander",
+        name: "Lunar Lander",
+        description: "Set down gently on a landing pad before the fuel runs out",
+        players: 1,
+        controls: "LEFT/RIGHT rotate, UP/DOWN throttle, SPACE full thrust, A descent assist",
+        factory: || Box::new(lunar_lander::LunarLander::new()),
+    },
+    GameEntry {
+        id: "battlezone",
+        name: "Battlezone",
+        description: "First-person 3D tank combat",
+        players: 1,
+        controls: "LEFT/RIGHT rotate, UP/DOWN move, SPACE fire",
+        factory: || Box::new(battlezone::Battlezone::new()),
+    },
+    GameEntry {
+        id: "tempest",
+        name: "Tempest",
+        description: "Hold the rim of the tube against climbing enemies",
+        players: 1,
+        controls: "LEFT/RIGHT move, SPACE fire",
+        factory: || Box::new(tempest::Tempest::new()),
+    },
+];
+
+/// Every registered entry, contents screen first.
+pub fn registry() -> impl Iterator<Item = &'static GameEntry> {
+    std::iter::once(&CONTENTS).chain(GAMES.iter())
+}
+
+/// Playable games in menu order (excludes the contents screen).
+pub fn games() -> &'static [GameEntry] {
+    &GAMES
+}
+
+/// Find an entry by its stable ID.
+pub fn lookup(id: &str) -> Option<&'static GameEntry> {
+    registry().find(|e| e.id == id)
+}
+
+/// Build only the game with the given ID.
+pub fn create(id: &str) -> Option<Box<dyn Game + Send>> {
+    lookup(id).map(GameEntry::create)
+}
@@ -0,0 +1,84 @@
+use vectorcade_games::{Arcade, all_games, create, games, lookup, registry};
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+/// Reports a single key as just pressed (or nothing, for `None`).
+struct Press(Option<Key>);
+impl InputState for Press {
+    fn key(&self, k: Key) -> Button {
+        if Some(k) == self.0 { Button { is_down: true, went_down: true, went_up: false } } else { Button::UP }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+fn step(g: &mut Arcade, key: Option<Key>) {
+    let input = Press(key);
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(12345);
+    let mut ctx = GameCtx {
+        input: &input,
+        audio: &audio,
+        rng: &mut rng,
+        screen: ScreenInfo::default(),
+        now_s: 0.0,
+    };
+    g.update(&mut ctx, 0.016);
+}
+
+#[test]
+fn menu_lists_every_game_from_metadata() {
+    let g = Arcade::new();
+    let names: Vec<&str> = all_games().iter().skip(1).map(|g| g.metadata().name).collect();
+    assert_eq!(g.menu.entries(), names.as_slice());
+}
+
+#[test]
+fn registry_ids_are_unique_and_match_metadata() {
+    let ids: Vec<&str> = registry().map(|e| e.id).collect();
+    for (i, id) in ids.iter().enumerate() {
+        assert!(!ids[i + 1..].contains(id), "duplicate id {id}");
+    }
+    for entry in registry() {
+        assert_eq!(entry.create().metadata().name, entry.name, "{}", entry.id);
+    }
+    assert_eq!(registry().count(), games().len() + 1);
+}
+
+#[test]
+fn registry_lookup_and_create_by_id() {
+    assert_eq!(lookup("asteroids").map(|e| e.name), Some("Asteroids"));
+    assert!(lookup("defender").is_none());
+    assert_eq!(create("tempest").map(|g| g.metadata().name), Some("Tempest"));
+    assert!(create("defender").is_none());
+}
+
+#[test]
+fn arcade_launches_and_returns_to_menu() {
+    let mut g = Arcade::new();
+    step(&mut g, Some(Key::Down));
+    step(&mut g, Some(Key::Enter));
+    assert_eq!(g.active_id(), Some("pong"));
+    assert_eq!(g.metadata().name, "Pong");
+    step(&mut g, None);
+    step(&mut g, Some(Key::Escape));
+    assert_eq!(g.active_id(), None, "Escape returns to the menu");
+    assert_eq!(g.menu.cursor(), 1, "cursor is kept on return");
+}
+
+#[test]
+fn arcade_deep_links_by_id() {
+    let mut g = Arcade::new();
+    let mut rng = Xorshift64::new(12345);
+    let mut ctx = GameCtx { input: &Press(None), audio: &NoAudio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    assert!(g.launch("tempest", &mut ctx));
+    assert_eq!(g.active_id(), Some("tempest"));
+    assert!(!g.launch("defender", &mut ctx));
+    assert_eq!(g.active_id(), Some("tempest"), "unknown IDs leave the game running");
+    assert!(g.launch("contents", &mut ctx));
+    assert_eq!(g.active_id(), None, "the contents ID returns to the menu");
+}
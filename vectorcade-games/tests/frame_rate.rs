@@ -0,0 +1,84 @@
+use asteroids::Asteroids;
+use battlezone::Battlezone;
+use lunar_lander::LunarLander;
+use pong::Pong;
+use tempest::Tempest;
+use vectorcade_shared::Xorshift64;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+/// Holds thrust/fire/turn for the whole run; Space is fresh on the first frame
+/// to leave the instruction screen.
+struct Held { first: bool }
+impl InputState for Held {
+    fn key(&self, k: Key) -> Button {
+        match k {
+            Key::Space | Key::Up | Key::Left | Key::W => {
+                Button { is_down: true, went_down: self.first && k == Key::Space, went_up: false }
+            }
+            _ => Button::UP,
+        }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+/// Run `game` for `seconds` at `hz` display frames per second.
+fn run<G: Game>(mut game: G, hz: u32, seconds: u32) -> G {
+    let audio = NoAudio;
+    let mut rng = Xorshift64::new(2024);
+    let dt = 1.0 / hz as f32;
+    let mut ctx = GameCtx { input: &Held { first: true }, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+    game.reset(&mut ctx);
+    game.update(&mut ctx, dt);
+    for _ in 1..hz * seconds {
+        let input = Held { first: false };
+        let mut ctx = GameCtx { input: &input, audio: &audio, rng: &mut rng, screen: ScreenInfo::default(), now_s: 0.0 };
+        game.update(&mut ctx, dt);
+    }
+    game
+}
+
+fn at_rates<G: Game, S: PartialEq + std::fmt::Debug>(new: fn() -> G, state: fn(&G) -> S) {
+    let reference = state(&run(new(), 60, 3));
+    for hz in [30, 144] {
+        assert_eq!(state(&run(new(), hz, 3)), reference, "{hz} Hz differs from 60 Hz");
+    }
+}
+
+#[test]
+fn pong_is_frame_rate_independent() {
+    at_rates(Pong::new, |g| (g.ball, g.vel, g.paddle_l, g.score_l, g.score_r));
+}
+
+#[test]
+fn asteroids_is_frame_rate_independent() {
+    at_rates(Asteroids::new, |g| {
+        let rocks: Vec<_> = g.asteroids.iter().map(|a| a.pos).collect();
+        (g.ship.pos, g.ship.angle, g.bullets.len(), rocks, g.score, g.lives, g.respawn_timer)
+    });
+}
+
+#[test]
+fn lunar_lander_is_frame_rate_independent() {
+    at_rates(LunarLander::new, |g| (g.lander.pos, g.lander.vel, g.lander.fuel, g.score));
+}
+
+#[test]
+fn battlezone_is_frame_rate_independent() {
+    at_rates(Battlezone::new, |g| {
+        let enemies: Vec<_> = g.enemies.iter().map(|e| e.pos).collect();
+        (g.pos, g.angle, g.shots.len(), enemies, g.score)
+    });
+}
+
+#[test]
+fn tempest_is_frame_rate_independent() {
+    at_rates(Tempest::new, |g| {
+        let enemies: Vec<_> = g.enemies.iter().map(|e| (e.segment, e.depth)).collect();
+        (g.blaster.segment, g.shots.len(), enemies, g.score, g.lives)
+    });
+}
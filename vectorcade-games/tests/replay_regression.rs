@@ -0,0 +1,83 @@
+use arcade_kit::replay::{Recorder, Replay, play};
+use asteroids::Asteroids;
+use pong::Pong;
+use vectorcade_shared::game::{AudioOut, Game, GameCtx, ScreenInfo};
+use vectorcade_shared::input::{Axis, Button, InputState, Key};
+
+/// Frame-indexed script: start on frame 0, then steer, thrust and fire.
+struct Script(usize);
+impl InputState for Script {
+    fn key(&self, k: Key) -> Button {
+        let f = self.0;
+        let held = match k {
+            Key::Space => f == 0 || (f / 7).is_multiple_of(3),
+            Key::Up | Key::W => (20..90).contains(&f),
+            Key::Left => (40..70).contains(&f),
+            Key::Down | Key::S => (100..150).contains(&f),
+            _ => false,
+        };
+        let was = f > 0 && Script(f - 1).key(k).is_down;
+        Button { is_down: held, went_down: held && !was, went_up: !held && was }
+    }
+    fn axis(&self, _a: Axis) -> f32 { 0.0 }
+    fn pointer(&self) -> Option<vectorcade_shared::input::Pointer> { None }
+}
+
+struct NoAudio;
+impl AudioOut for NoAudio {}
+
+/// Play `frames` scripted frames into `game` while recording them.
+fn record(game: &mut dyn Game, seed: u64, frames: usize) -> Replay {
+    let audio = NoAudio;
+    let mut rec = Recorder::new(seed);
+    let mut rng = rec.rng();
+    let mut ctx = GameCtx {
+        input: &Script(0),
+        audio: &audio,
+        rng: &mut rng,
+        screen: ScreenInfo::default(),
+        now_s: 0.0,
+    };
+    game.reset(&mut ctx);
+    for f in 0..frames {
+        let input = Script(f);
+        let dt = if f % 2 == 0 { 1.0 / 60.0 } else { 1.0 / 45.0 };
+        rec.record(&input, dt);
+        let mut ctx = GameCtx {
+            input: &input,
+            audio: &audio,
+            rng: &mut rng,
+            screen: ScreenInfo::default(),
+            now_s: 0.0,
+        };
+        game.update(&mut ctx, dt);
+    }
+    rec.finish()
+}
+
+#[test]
+fn pong_replay_matches_live_session() {
+    let mut live = Pong::new();
+    let replay = record(&mut live, 99, 240);
+    let replay = Replay::from_bytes(&replay.to_bytes()).expect("valid replay");
+    let mut copy = Pong::new();
+    play(&replay, &mut copy, &NoAudio);
+    assert!(!copy.showing_instructions);
+    assert_eq!((copy.ball, copy.vel), (live.ball, live.vel));
+    assert_eq!((copy.paddle_l, copy.paddle_r), (live.paddle_l, live.paddle_r));
+    assert_eq!((copy.score_l, copy.score_r), (live.score_l, live.score_r));
+}
+
+#[test]
+fn asteroids_replay_matches_live_session() {
+    let mut live = Asteroids::new();
+    let replay = record(&mut live, 4242, 300);
+    let replay = Replay::from_bytes(&replay.to_bytes()).expect("valid replay");
+    let mut copy = Asteroids::new();
+    play(&replay, &mut copy, &NoAudio);
+    assert_eq!((copy.ship.pos, copy.ship.vel, copy.ship.angle), (live.ship.pos, live.ship.vel, live.ship.angle));
+    assert_eq!(copy.bullets.len(), live.bullets.len());
+    let rocks = |g: &Asteroids| g.asteroids.iter().map(|a| a.pos).collect::<Vec<_>>();
+    assert_eq!(rocks(&copy), rocks(&live));
+    assert_eq!((copy.score, copy.lives), (live.score, live.lives));
+}